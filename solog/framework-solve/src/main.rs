@@ -1,100 +1,17 @@
 use std::{error::Error, io::{BufRead, BufReader, Read, Write}, net::TcpStream, str::FromStr};
 
 use solana_program::{instruction::{AccountMeta, Instruction}};
-use solana_sdk::{exit, pubkey};
+use solana_sdk::exit;
 use sha2::Sha256;
 use sha2::Digest;
 use borsh::{to_vec, BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
-
-pub const MAX_COLLABORATORS: usize = 3;
-
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub enum HeapCommand {
-    Allocate {
-        size: u64,
-    },
-    Edit {
-        index: u64,
-        data: Vec<u8>,
-        resize: bool,
-    },
-    Search {
-        index: u64,
-    }
-}
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub struct HeapKit {
-    pub commands: Vec<HeapCommand>
-}
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub struct Post {
-    pub claps: u8,
-    pub comment_count: u8,
-
-    pub collaborators: [Pubkey; MAX_COLLABORATORS],
-    pub collaborator_count: u8,
-
-    pub author: Pubkey,
-    pub title: Vec<u8>,
-    pub content: Vec<u8>,
-}
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub struct Comment {
-    pub claps: u8,
-    pub order: u8,
-    pub author: Pubkey,
-    pub content: Vec<u8>,
-}
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub enum SologData {
-    Post(Post),
-    Comment(Comment),
-}
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub enum SologInstruction {
-    /// Create a new post
-    /// accounts:
-    ///  0. author
-    ///  1. post pda
-    ///  2. system_program
-    CreatePost { title: Vec<u8>, content: Vec<u8> },
-
-    /// Add a collaborator to a post
-    /// accounts:
-    ///  0. author
-    ///  1. collaborator
-    ///  2. post pda
-    AddCollaborator,
-
-    /// Clap for a post
-    /// accounts:
-    ///  0. viewer
-    ///  1. post or comment pda
-    ///  2. system_program
-    Clap { data: Vec<u8> },
-
-    /// Comment on a post
-    /// accounts:
-    ///  0. author
-    ///  1. post pda
-    ///  2. comment pda
-    ///  3. system_program
-    AddComment { content: Vec<u8> },
-
-    /// Edit a comment
-    /// accounts:
-    /// 0. author
-    /// 1. comment pda
-    /// 2. system_program
-    EditComment { content: Vec<u8> },
-}
+use solog_types::{
+    builder,
+    event::SologEvent,
+    instruction::{format_heap_dump, HeapCommand, HeapKit, SologInstruction},
+    state::{Comment, Post, SologData},
+};
 
 pub struct ProofOfWork {
     pub prefix: String,
@@ -150,7 +67,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     writeln!(stream, "nonce: {}", nonce)?;
 
 
-    let program_id = pubkey!("so1og11111111111111111111111111111111111111");
+    let program_id = solog_types::ID;
     let user = Pubkey::from_str(&get_line(&mut reader)?)?;
     let post = Pubkey::from_str(&get_line(&mut reader)?)?;
 
@@ -165,30 +82,17 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // add comment for an example, you can delete it if you want
     let content = "hacker".as_bytes().to_vec();
-    let instruction = SologInstruction::AddComment {
-        content,
-    };
-    let (comment, _) = Pubkey::find_program_address(
-        &[
-            b"comment",
-            &post.to_bytes()[..16],
-            &user.to_bytes()[..16],
-            &0_u8.to_le_bytes(),
-        ],
-        &program_id,
-    );
-    let add_comment_ix = Instruction {
-        program_id,
-        accounts: vec![
-            AccountMeta::new(user, true),
-            AccountMeta::new(post, false),
-            AccountMeta::new(comment, false),
-            AccountMeta::new_readonly(solana_program::system_program::id(), false),
-        ],
-        data: to_vec(&instruction).unwrap(),
-    };
+    let add_comment_ix = builder::add_comment(&program_id, &user, &post, 0, content);
     instructions.push(add_comment_ix);
 
+    // the challenge is built with the dev tools, clap data runs heap commands
+    // and dumped chunks are printed as a table below
+    let heapkit = HeapKit {
+        commands: vec![HeapCommand::Dump { from: 1, count: 8 }],
+    };
+    let dump_ix = builder::clap(&program_id, &user, &post, to_vec(&heapkit)?);
+    instructions.push(dump_ix);
+
     // --------------------------------
     // you don't need to modify code blow
     
@@ -201,5 +105,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     stream.read_to_end(&mut response)?;
     let response = String::from_utf8(response)?;
     println!("{}", response);
+
+    // the server echoes the transaction logs, print the solog events in them
+    let logs: Vec<&str> = response.lines().collect();
+    for event in SologEvent::from_logs(&logs) {
+        match event {
+            SologEvent::HeapDumped { chunks } => print!("{}", format_heap_dump(&chunks)),
+            event => println!("event: {:?}", event),
+        }
+    }
     Ok(())
 }
@@ -0,0 +1,170 @@
+//! Drive random heap kit commands against a host model of the SBF heap and
+//! check that the chunk chain stays intact
+//!
+//! Run with `cargo fuzz run heap_kit` from `chall/`.
+
+#![no_main]
+
+use std::{
+    alloc::{GlobalAlloc, Layout},
+    mem::size_of,
+    ptr::null,
+};
+
+use arbitrary::Arbitrary;
+use chall::{
+    dev::run_commands,
+    heap::{AllocatorHandler, HeapRegion, SologAllocator},
+    processor::{HeapCommand, MAX_HEAP_DEV_COUNT},
+};
+use libfuzzer_sys::fuzz_target;
+use solana_program::entrypoint::HEAP_LENGTH;
+
+/// Words kept zeroed on both sides of the heap to catch writes outside of it
+const GUARD_WORDS: usize = 64;
+
+/// Allocations made before the heap kit runs, bounded like a real instruction
+const MAX_PRELUDE: usize = 16;
+
+#[derive(Arbitrary, Debug)]
+enum Command {
+    Allocate { size: u16 },
+    Edit { index: u8, data: Vec<u8>, resize: bool },
+    Search { index: u8 },
+    Dump { from: u8, count: u8 },
+}
+
+impl From<Command> for HeapCommand {
+    fn from(command: Command) -> Self {
+        match command {
+            Command::Allocate { size } => HeapCommand::Allocate { size: size as u64 },
+            Command::Edit { index, data, resize } => HeapCommand::Edit {
+                index: index as u64,
+                data,
+                resize,
+            },
+            Command::Search { index } => HeapCommand::Search { index: index as u64 },
+            Command::Dump { from, count } => HeapCommand::Dump {
+                from: from as u64,
+                count: count as u64,
+            },
+        }
+    }
+}
+
+#[derive(Arbitrary, Debug)]
+struct Input {
+    prelude: Vec<u16>,
+    commands: Vec<Command>,
+}
+
+/// Host heap mimicking the SBF heap, surrounded by guard words
+struct Heap {
+    buffer: Vec<u64>,
+    region: HeapRegion,
+    allocator: SologAllocator,
+    chunks: usize,
+}
+
+impl Heap {
+    fn new() -> Self {
+        let mut buffer = vec![0u64; HEAP_LENGTH / size_of::<u64>() + 2 * GUARD_WORDS];
+        let region = unsafe {
+            HeapRegion::from_buffer(&mut buffer[GUARD_WORDS..GUARD_WORDS + HEAP_LENGTH / 8])
+        };
+
+        Self {
+            buffer,
+            region,
+            allocator: SologAllocator::new(region),
+            chunks: 0,
+        }
+    }
+
+    /// Allocate like `Vec::with_capacity`, `None` when the heap is exhausted
+    fn allocate(&mut self, size: usize) -> Option<*const u8> {
+        if size == 0 {
+            return Some(null());
+        }
+
+        let ptr = unsafe {
+            self.allocator
+                .alloc(Layout::from_size_align(size, 1).unwrap())
+        };
+        if ptr.is_null() {
+            return None;
+        }
+
+        self.chunks += 1;
+        Some(ptr)
+    }
+
+    fn check(&self) {
+        let (before, rest) = self.buffer.split_at(GUARD_WORDS);
+        let after = &rest[HEAP_LENGTH / 8..];
+        assert!(before.iter().all(|&word| word == 0), "write below the heap");
+        assert!(after.iter().all(|&word| word == 0), "write above the heap");
+
+        let pos = unsafe { *self.region.pos_ptr() };
+        if pos == 0 {
+            assert_eq!(self.chunks, 0);
+            return;
+        }
+        assert!(pos >= self.region.bottom() && pos <= self.region.top());
+
+        // every chunk starts with a pointer to its length word, followed by
+        // the data, up to the bump position
+        let mut link = self.region.bottom();
+        let mut chunks = 0;
+        while link < pos {
+            let length_pos = unsafe { (link as *const usize).read_unaligned() };
+            assert!(
+                length_pos >= link + size_of::<usize>() && length_pos + size_of::<usize>() <= pos,
+                "chunk {} points outside the heap",
+                chunks
+            );
+
+            let length = unsafe { (length_pos as *const usize).read_unaligned() };
+            assert!(length <= HEAP_LENGTH, "chunk {} is too long", chunks);
+
+            link = length_pos + size_of::<usize>() + length;
+            assert!(link <= pos, "chunk {} overruns the heap", chunks);
+            chunks += 1;
+        }
+
+        assert_eq!(chunks, self.chunks, "chunks unreachable from the heap bottom");
+    }
+}
+
+fuzz_target!(|input: Input| {
+    let mut heap = Heap::new();
+
+    // on chain the instruction data is already on the heap when the kit runs
+    for size in std::iter::once(8).chain(input.prelude.into_iter().take(MAX_PRELUDE)) {
+        if heap.allocate(size as usize).is_none() {
+            return;
+        }
+    }
+    heap.check();
+
+    let handler = AllocatorHandler::new(heap.region);
+    for command in input.commands.into_iter().take(MAX_HEAP_DEV_COUNT) {
+        let mut exhausted = false;
+
+        unsafe {
+            run_commands(&handler, vec![command.into()], |size| {
+                heap.allocate(size).unwrap_or_else(|| {
+                    exhausted = true;
+                    null()
+                })
+            })
+            .unwrap();
+        }
+
+        // running out of heap aborts the transaction
+        if exhausted {
+            return;
+        }
+        heap.check();
+    }
+});
@@ -0,0 +1,987 @@
+//! Helpers for other programs to invoke solog through cpi.
+//!
+//! Every function takes the solog program account, the accounts of the
+//! instruction in the order documented on [`SologInstruction`] and the seeds
+//! of any pda of the calling program that has to sign. The instruction
+//! itself is built by [`builder`], so the pdas passed in must be the ones it
+//! derives.
+//!
+//! [`SologInstruction`]: crate::processor::SologInstruction
+
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::Instruction,
+    program::{get_return_data, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    builder,
+    processor::{CommentSummary, PostSummary, ReactionKind},
+};
+
+pub struct CreatePost<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct AddCollaborator<'a> {
+    pub author: AccountInfo<'a>,
+    pub collaborator: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+}
+
+pub struct Clap<'a> {
+    pub viewer: AccountInfo<'a>,
+    pub post_or_comment: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+}
+
+pub struct AddComment<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub comment: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub block_list: AccountInfo<'a>,
+    pub config: AccountInfo<'a>,
+    pub rate_limit: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct EditComment<'a> {
+    pub author: AccountInfo<'a>,
+    pub comment: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub block_list: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct PinComment<'a> {
+    pub moderator: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub comment: AccountInfo<'a>,
+}
+
+pub struct LockComments<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+}
+
+pub struct BlockCommenter<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub commenter: AccountInfo<'a>,
+    pub block_list: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct UnblockCommenter<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub commenter: AccountInfo<'a>,
+    pub block_list: AccountInfo<'a>,
+}
+
+pub struct InitConfig<'a> {
+    pub admin: AccountInfo<'a>,
+    pub config: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub program_data: AccountInfo<'a>,
+}
+
+pub struct UpdateConfig<'a> {
+    pub admin: AccountInfo<'a>,
+    pub config: AccountInfo<'a>,
+}
+
+pub struct SetPostPrice<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct PurchaseAccess<'a> {
+    pub reader: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub access_receipt: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub author: AccountInfo<'a>,
+    pub collaborators: Vec<AccountInfo<'a>>,
+}
+
+pub struct Follow<'a> {
+    pub follower: AccountInfo<'a>,
+    pub following: AccountInfo<'a>,
+    pub follower_profile: AccountInfo<'a>,
+    pub following_profile: AccountInfo<'a>,
+    pub follow: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct Unfollow<'a> {
+    pub follower: AccountInfo<'a>,
+    pub following: AccountInfo<'a>,
+    pub follower_profile: AccountInfo<'a>,
+    pub following_profile: AccountInfo<'a>,
+    pub follow: AccountInfo<'a>,
+}
+
+pub struct SetApprovalThreshold<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+}
+
+pub struct ProposeEdit<'a> {
+    pub proposer: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub proposal: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct ApproveProposal<'a> {
+    pub approver: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub proposal: AccountInfo<'a>,
+}
+
+pub struct ExecuteProposal<'a> {
+    pub executor: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub proposal: AccountInfo<'a>,
+    pub proposer: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+}
+
+pub struct CancelProposal<'a> {
+    pub canceller: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub proposal: AccountInfo<'a>,
+    pub proposer: AccountInfo<'a>,
+}
+
+pub struct GetPostSummary<'a> {
+    pub post: AccountInfo<'a>,
+}
+
+pub struct GetComment<'a> {
+    pub post: AccountInfo<'a>,
+    pub comment: AccountInfo<'a>,
+}
+
+pub struct React<'a> {
+    pub viewer: AccountInfo<'a>,
+    pub target: AccountInfo<'a>,
+    pub reactions: AccountInfo<'a>,
+    pub reaction: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct CreateBounty<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub bounty: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct AwardBounty<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub comment: AccountInfo<'a>,
+    pub comment_author: AccountInfo<'a>,
+    pub bounty: AccountInfo<'a>,
+}
+
+pub struct ReclaimBounty<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub bounty: AccountInfo<'a>,
+}
+
+pub struct Repost<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub original: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct Flag<'a> {
+    pub flagger: AccountInfo<'a>,
+    pub target: AccountInfo<'a>,
+    pub flag: AccountInfo<'a>,
+    pub config: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct Unhide<'a> {
+    pub reviewer: AccountInfo<'a>,
+    pub target: AccountInfo<'a>,
+    pub config: AccountInfo<'a>,
+    /// Post of the comment, or the target itself
+    pub post: AccountInfo<'a>,
+    /// Pairs of flag pda and flagger
+    pub flags: Vec<(AccountInfo<'a>, AccountInfo<'a>)>,
+}
+
+pub struct SetPostExpiry<'a> {
+    pub author: AccountInfo<'a>,
+    pub post: AccountInfo<'a>,
+    pub system_program: AccountInfo<'a>,
+    pub payer: Option<AccountInfo<'a>>,
+}
+
+pub struct CloseExpired<'a> {
+    pub post: AccountInfo<'a>,
+    pub author: AccountInfo<'a>,
+    pub bounty: AccountInfo<'a>,
+    pub rent_payer: AccountInfo<'a>,
+    /// Pairs of comment pda and rent payer of the comment
+    pub comments: Vec<(AccountInfo<'a>, AccountInfo<'a>)>,
+}
+
+pub fn create_post<'a>(
+    program: &AccountInfo<'a>,
+    accounts: CreatePost<'a>,
+    title: Vec<u8>,
+    content: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::create_post(program.key, accounts.author.key, title, content),
+        vec![accounts.author, accounts.post, accounts.system_program],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+pub fn add_collaborator<'a>(
+    program: &AccountInfo<'a>,
+    accounts: AddCollaborator<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::add_collaborator(
+            program.key,
+            accounts.author.key,
+            accounts.collaborator.key,
+            accounts.post.key,
+        ),
+        vec![accounts.author, accounts.collaborator, accounts.post],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn set_moderator<'a>(
+    program: &AccountInfo<'a>,
+    accounts: AddCollaborator<'a>,
+    moderator: bool,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::set_moderator(
+            program.key,
+            accounts.author.key,
+            accounts.collaborator.key,
+            accounts.post.key,
+            moderator,
+        ),
+        vec![accounts.author, accounts.collaborator, accounts.post],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn clap<'a>(
+    program: &AccountInfo<'a>,
+    accounts: Clap<'a>,
+    data: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::clap(
+            program.key,
+            accounts.viewer.key,
+            accounts.post_or_comment.key,
+            data,
+        ),
+        vec![accounts.viewer, accounts.post_or_comment, accounts.system_program],
+        None,
+        signer_seeds,
+    )
+}
+
+/// `order` is the current `comment_count` of the post
+pub fn add_comment<'a>(
+    program: &AccountInfo<'a>,
+    accounts: AddComment<'a>,
+    order: u8,
+    content: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::add_comment(
+            program.key,
+            accounts.author.key,
+            accounts.post.key,
+            order,
+            content,
+        ),
+        vec![
+            accounts.author,
+            accounts.post,
+            accounts.comment,
+            accounts.system_program,
+            accounts.block_list,
+            accounts.config,
+            accounts.rate_limit,
+        ],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+/// `order` is the `order` of the comment being edited
+pub fn edit_comment<'a>(
+    program: &AccountInfo<'a>,
+    accounts: EditComment<'a>,
+    order: u8,
+    content: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::edit_comment(
+            program.key,
+            accounts.author.key,
+            accounts.post.key,
+            order,
+            content,
+        ),
+        vec![
+            accounts.author,
+            accounts.comment,
+            accounts.system_program,
+            accounts.post,
+            accounts.block_list,
+        ],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+pub fn pin_comment<'a>(
+    program: &AccountInfo<'a>,
+    accounts: PinComment<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::pin_comment(
+            program.key,
+            accounts.moderator.key,
+            accounts.post.key,
+            accounts.comment.key,
+        ),
+        vec![accounts.moderator, accounts.post, accounts.comment],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn unpin_comment<'a>(
+    program: &AccountInfo<'a>,
+    accounts: PinComment<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::unpin_comment(
+            program.key,
+            accounts.moderator.key,
+            accounts.post.key,
+            accounts.comment.key,
+        ),
+        vec![accounts.moderator, accounts.post, accounts.comment],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn lock_comments<'a>(
+    program: &AccountInfo<'a>,
+    accounts: LockComments<'a>,
+    locked: bool,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::lock_comments(program.key, accounts.author.key, accounts.post.key, locked),
+        vec![accounts.author, accounts.post],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn block_commenter<'a>(
+    program: &AccountInfo<'a>,
+    accounts: BlockCommenter<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::block_commenter(
+            program.key,
+            accounts.author.key,
+            accounts.post.key,
+            accounts.commenter.key,
+        ),
+        vec![
+            accounts.author,
+            accounts.post,
+            accounts.commenter,
+            accounts.block_list,
+            accounts.system_program,
+        ],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+pub fn unblock_commenter<'a>(
+    program: &AccountInfo<'a>,
+    accounts: UnblockCommenter<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::unblock_commenter(
+            program.key,
+            accounts.author.key,
+            accounts.post.key,
+            accounts.commenter.key,
+        ),
+        vec![
+            accounts.author,
+            accounts.post,
+            accounts.commenter,
+            accounts.block_list,
+        ],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn init_config<'a>(
+    program: &AccountInfo<'a>,
+    accounts: InitConfig<'a>,
+    comment_slot_window: u64,
+    flag_threshold: u32,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::init_config(
+            program.key,
+            accounts.admin.key,
+            comment_slot_window,
+            flag_threshold,
+        ),
+        vec![
+            accounts.admin,
+            accounts.config,
+            accounts.system_program,
+            accounts.program_data,
+        ],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn update_config<'a>(
+    program: &AccountInfo<'a>,
+    accounts: UpdateConfig<'a>,
+    comment_slot_window: u64,
+    flag_threshold: u32,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::update_config(
+            program.key,
+            accounts.admin.key,
+            comment_slot_window,
+            flag_threshold,
+        ),
+        vec![accounts.admin, accounts.config],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn set_post_price<'a>(
+    program: &AccountInfo<'a>,
+    accounts: SetPostPrice<'a>,
+    price: u64,
+    gated_content: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::set_post_price(
+            program.key,
+            accounts.author.key,
+            accounts.post.key,
+            price,
+            gated_content,
+        ),
+        vec![accounts.author, accounts.post, accounts.system_program],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+pub fn purchase_access<'a>(
+    program: &AccountInfo<'a>,
+    accounts: PurchaseAccess<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let collaborators: Vec<_> = accounts
+        .collaborators
+        .iter()
+        .map(|collaborator| *collaborator.key)
+        .collect();
+    let instruction = builder::purchase_access(
+        program.key,
+        accounts.reader.key,
+        accounts.post.key,
+        accounts.author.key,
+        &collaborators,
+    );
+
+    let mut infos = vec![
+        accounts.reader,
+        accounts.post,
+        accounts.access_receipt,
+        accounts.system_program,
+        accounts.author,
+    ];
+    infos.extend(accounts.collaborators);
+
+    invoke(instruction, infos, None, signer_seeds)
+}
+
+pub fn follow<'a>(
+    program: &AccountInfo<'a>,
+    accounts: Follow<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::follow(program.key, accounts.follower.key, accounts.following.key),
+        vec![
+            accounts.follower,
+            accounts.following,
+            accounts.follower_profile,
+            accounts.following_profile,
+            accounts.follow,
+            accounts.system_program,
+        ],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+pub fn unfollow<'a>(
+    program: &AccountInfo<'a>,
+    accounts: Unfollow<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::unfollow(program.key, accounts.follower.key, accounts.following.key),
+        vec![
+            accounts.follower,
+            accounts.following,
+            accounts.follower_profile,
+            accounts.following_profile,
+            accounts.follow,
+        ],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn set_approval_threshold<'a>(
+    program: &AccountInfo<'a>,
+    accounts: SetApprovalThreshold<'a>,
+    threshold: u8,
+    require_author: bool,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::set_approval_threshold(
+            program.key,
+            accounts.author.key,
+            accounts.post.key,
+            threshold,
+            require_author,
+        ),
+        vec![accounts.author, accounts.post],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn propose_edit<'a>(
+    program: &AccountInfo<'a>,
+    accounts: ProposeEdit<'a>,
+    content: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::propose_edit(
+            program.key,
+            accounts.proposer.key,
+            accounts.post.key,
+            content,
+        ),
+        vec![
+            accounts.proposer,
+            accounts.post,
+            accounts.proposal,
+            accounts.system_program,
+        ],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+/// `proposer` is the proposer of the edit being approved
+pub fn approve_proposal<'a>(
+    program: &AccountInfo<'a>,
+    accounts: ApproveProposal<'a>,
+    proposer: &Pubkey,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::approve_proposal(
+            program.key,
+            accounts.approver.key,
+            accounts.post.key,
+            proposer,
+        ),
+        vec![accounts.approver, accounts.post, accounts.proposal],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn execute_proposal<'a>(
+    program: &AccountInfo<'a>,
+    accounts: ExecuteProposal<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::execute_proposal(
+            program.key,
+            accounts.executor.key,
+            accounts.post.key,
+            accounts.proposer.key,
+        ),
+        vec![
+            accounts.executor,
+            accounts.post,
+            accounts.proposal,
+            accounts.proposer,
+            accounts.system_program,
+        ],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn cancel_proposal<'a>(
+    program: &AccountInfo<'a>,
+    accounts: CancelProposal<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::cancel_proposal(
+            program.key,
+            accounts.canceller.key,
+            accounts.post.key,
+            accounts.proposer.key,
+        ),
+        vec![
+            accounts.canceller,
+            accounts.post,
+            accounts.proposal,
+            accounts.proposer,
+        ],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn react<'a>(
+    program: &AccountInfo<'a>,
+    accounts: React<'a>,
+    kind: ReactionKind,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::react(program.key, accounts.viewer.key, accounts.target.key, kind),
+        vec![
+            accounts.viewer,
+            accounts.target,
+            accounts.reactions,
+            accounts.reaction,
+            accounts.system_program,
+        ],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+pub fn create_bounty<'a>(
+    program: &AccountInfo<'a>,
+    accounts: CreateBounty<'a>,
+    amount: u64,
+    expires_at: i64,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::create_bounty(
+            program.key,
+            accounts.author.key,
+            accounts.post.key,
+            amount,
+            expires_at,
+        ),
+        vec![
+            accounts.author,
+            accounts.post,
+            accounts.bounty,
+            accounts.system_program,
+        ],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+pub fn award_bounty<'a>(
+    program: &AccountInfo<'a>,
+    accounts: AwardBounty<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::award_bounty(
+            program.key,
+            accounts.author.key,
+            accounts.post.key,
+            accounts.comment.key,
+            accounts.comment_author.key,
+        ),
+        vec![
+            accounts.author,
+            accounts.post,
+            accounts.comment,
+            accounts.comment_author,
+            accounts.bounty,
+        ],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn reclaim_bounty<'a>(
+    program: &AccountInfo<'a>,
+    accounts: ReclaimBounty<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::reclaim_bounty(program.key, accounts.author.key, accounts.post.key),
+        vec![accounts.author, accounts.post, accounts.bounty],
+        None,
+        signer_seeds,
+    )
+}
+
+pub fn repost<'a>(
+    program: &AccountInfo<'a>,
+    accounts: Repost<'a>,
+    title: Vec<u8>,
+    content: Vec<u8>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::repost(
+            program.key,
+            accounts.author.key,
+            accounts.original.key,
+            title,
+            content,
+        ),
+        vec![
+            accounts.author,
+            accounts.post,
+            accounts.original,
+            accounts.system_program,
+        ],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+pub fn flag<'a>(
+    program: &AccountInfo<'a>,
+    accounts: Flag<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::flag(program.key, accounts.flagger.key, accounts.target.key),
+        vec![
+            accounts.flagger,
+            accounts.target,
+            accounts.flag,
+            accounts.config,
+            accounts.system_program,
+        ],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+pub fn unhide<'a>(
+    program: &AccountInfo<'a>,
+    accounts: Unhide<'a>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let flaggers: Vec<_> = accounts.flags.iter().map(|(_, flagger)| *flagger.key).collect();
+    let instruction = builder::unhide(
+        program.key,
+        accounts.reviewer.key,
+        accounts.post.key,
+        accounts.target.key,
+        &flaggers,
+    );
+
+    let mut infos = vec![accounts.reviewer, accounts.target, accounts.config, accounts.post];
+    for (flag, flagger) in accounts.flags {
+        infos.push(flag);
+        infos.push(flagger);
+    }
+
+    invoke(instruction, infos, None, signer_seeds)
+}
+
+pub fn set_post_expiry<'a>(
+    program: &AccountInfo<'a>,
+    accounts: SetPostExpiry<'a>,
+    expires_at: Option<i64>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    invoke(
+        builder::set_post_expiry(
+            program.key,
+            accounts.author.key,
+            accounts.post.key,
+            expires_at,
+        ),
+        vec![accounts.author, accounts.post, accounts.system_program],
+        accounts.payer,
+        signer_seeds,
+    )
+}
+
+/// Close comments of an expired post, nothing has to sign
+pub fn close_expired<'a>(
+    program: &AccountInfo<'a>,
+    accounts: CloseExpired<'a>,
+) -> ProgramResult {
+    let comments: Vec<_> = accounts
+        .comments
+        .iter()
+        .map(|(comment, comment_rent_payer)| (*comment.key, *comment_rent_payer.key))
+        .collect();
+    let instruction = builder::close_expired(
+        program.key,
+        accounts.post.key,
+        accounts.author.key,
+        accounts.rent_payer.key,
+        &comments,
+    );
+
+    let mut infos = vec![
+        accounts.post,
+        accounts.author,
+        accounts.bounty,
+        accounts.rent_payer,
+    ];
+    for (comment, comment_rent_payer) in accounts.comments {
+        infos.push(comment);
+        infos.push(comment_rent_payer);
+    }
+
+    invoke(instruction, infos, None, &[])
+}
+
+/// Read a validated summary of a post, nothing has to sign
+pub fn get_post_summary<'a>(
+    program: &AccountInfo<'a>,
+    accounts: GetPostSummary<'a>,
+) -> Result<PostSummary, ProgramError> {
+    invoke(
+        builder::get_post_summary(program.key, accounts.post.key),
+        vec![accounts.post],
+        None,
+        &[],
+    )?;
+    return_data(program)
+}
+
+/// Read a validated summary of a comment, nothing has to sign
+pub fn get_comment<'a>(
+    program: &AccountInfo<'a>,
+    accounts: GetComment<'a>,
+) -> Result<CommentSummary, ProgramError> {
+    invoke(
+        builder::get_comment(program.key, accounts.post.key, accounts.comment.key),
+        vec![accounts.post, accounts.comment],
+        None,
+        &[],
+    )?;
+    return_data(program)
+}
+
+/// Decode the return data set by the solog program
+fn return_data<T: BorshDeserialize>(program: &AccountInfo) -> Result<T, ProgramError> {
+    match get_return_data() {
+        Some((program_id, data)) if program_id == *program.key => {
+            T::try_from_slice(&data).map_err(|_| ProgramError::InvalidAccountData)
+        }
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Invoke `instruction`, appending the optional payer to its accounts
+fn invoke<'a>(
+    instruction: Instruction,
+    mut account_infos: Vec<AccountInfo<'a>>,
+    payer: Option<AccountInfo<'a>>,
+    signer_seeds: &[&[&[u8]]],
+) -> ProgramResult {
+    let instruction = match payer {
+        Some(payer) => {
+            let instruction = builder::with_payer(instruction, payer.key);
+            account_infos.push(payer);
+            instruction
+        }
+        None => instruction,
+    };
+
+    invoke_signed(&instruction, &account_infos, signer_seeds)
+}
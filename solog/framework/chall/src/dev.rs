@@ -1,12 +1,35 @@
 use borsh::BorshDeserialize;
 use solana_program::{entrypoint::ProgramResult, msg};
 
-use crate::{entrypoint::AllocatorHandler, processor::{HeapCommand, HeapKit, MAX_HEAP_DEV_COUNT}};
+use crate::{
+    event::SologEvent,
+    heap::AllocatorHandler,
+    processor::{HeapChunk, HeapCommand, HeapKit, MAX_HEAP_DEV_COUNT, MAX_HEAP_DUMP_COUNT},
+};
 
 
 pub fn heap_kit(instruction_data: &[u8]) -> ProgramResult {
     let HeapKit { commands } = HeapKit::try_from_slice(instruction_data)?;
 
+    unsafe {
+        run_commands(&AllocatorHandler::SBF, commands, |size| {
+            let vec_ptr: Vec<u8> = Vec::with_capacity(size);
+            vec_ptr.as_ptr()
+        })
+    }
+}
+
+/// Run heap commands against the heap of `handler`, allocating through
+/// `allocate` so that a host heap model can stand in for the SBF heap
+///
+/// # Safety
+///
+/// Edits write through pointers read back from the heap chunks.
+pub unsafe fn run_commands(
+    handler: &AllocatorHandler,
+    commands: Vec<HeapCommand>,
+    mut allocate: impl FnMut(usize) -> *const u8,
+) -> ProgramResult {
     if commands.len() > MAX_HEAP_DEV_COUNT {
         msg!("Too many commands, skip");
         return Ok(());
@@ -15,23 +38,56 @@ pub fn heap_kit(instruction_data: &[u8]) -> ProgramResult {
     for command in commands {
         match command {
             HeapCommand::Allocate { size } => {
-                let vec_ptr: Vec<u8> = Vec::with_capacity(size as usize);
-                msg!("Allocated: {:p}", vec_ptr.as_ptr());
+                let vec_ptr = allocate(size as usize);
+                msg!("Allocated: {:p}", vec_ptr);
             }
             HeapCommand::Edit { index, data, resize } => {
-                unsafe {
-                    let (_, ptr) =  AllocatorHandler::search(index as usize);
-                    AllocatorHandler::edit(ptr, &data, resize);
+                let (_, ptr) =  handler.search(index as usize);
+                if ptr.is_null() {
+                    msg!("Index out of range");
+                    continue;
                 }
+                handler.edit(ptr, &data, resize);
             }
             HeapCommand::Search { index } => {
-                unsafe {
-                    let (len, ptr) = AllocatorHandler::search(index as usize);
-                    msg!("Index: {}, Length: {}, Ptr: {:p}", index, len, ptr);
-                }
+                let (len, ptr) = handler.search(index as usize);
+                msg!("Index: {}, Length: {}, Ptr: {:p}", index, len, ptr);
+            }
+            HeapCommand::Dump { from, count } => {
+                let chunks = dump(handler, from.max(1), count.min(MAX_HEAP_DUMP_COUNT as u64));
+                SologEvent::HeapDumped { chunks }.emit()?;
             }
         }
     }
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Walk `count` chunks starting at index `from`
+unsafe fn dump(handler: &AllocatorHandler, from: u64, count: u64) -> Vec<HeapChunk> {
+    let mut chunks = Vec::with_capacity(count as usize);
+    let (mut length, mut ptr) = handler.search(from as usize);
+
+    for index in from..from.saturating_add(count) {
+        if ptr.is_null() {
+            break;
+        }
+
+        // the data is followed by the address of the next chunk length
+        let next = (ptr.add(length) as *const usize).read_unaligned();
+        chunks.push(HeapChunk {
+            index,
+            address: ptr as u64,
+            length: length as u64,
+            next: next as u64,
+        });
+
+        if next == 0 {
+            break;
+        }
+        length = (next as *const usize).read_unaligned();
+        ptr = (next as *mut u8).add(8);
+    }
+
+    chunks
+}
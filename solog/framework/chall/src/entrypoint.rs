@@ -2,137 +2,29 @@ use {
     crate::{instruction, processor::SologInstruction},
     borsh::BorshDeserialize,
     solana_program::{
-        account_info::AccountInfo, entrypoint::{ProgramResult, HEAP_LENGTH, HEAP_START_ADDRESS}, msg, pubkey::Pubkey
+        account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey
     },
-    std::{alloc::Layout, mem::size_of, ptr::null_mut, usize},
 };
 
-pub struct AllocatorHandler;
-
-impl AllocatorHandler {
-    const BOTTOM_ADDRESS: usize = HEAP_START_ADDRESS as usize + size_of::<*mut u8>();
-
-    pub unsafe fn edit(pos: *mut u8, data: &[u8], resize: bool) {
-
-        let idx_length = *(pos.sub(8) as *const usize);
-        let mut idx_data_ptr = pos;
-        let idx_next_ptr = pos.add(idx_length) as *mut usize;
-        let data_len = data.len();
-
-        if idx_length > HEAP_LENGTH {
-            msg!("Data length is too long");
-            return;
-        }
-
-        if idx_length == 0 {
-            msg!("Data length is 0");
-            return;
-        }
-
-        if data_len > idx_length + 8 || data_len > 40 {
-            msg!("Data length is too long");
-            return;
-        }
-
-        // write data to idx_data_ptr
-        for i in 0..data_len {
-            idx_data_ptr.write_volatile(data[i]);
-            idx_data_ptr = idx_data_ptr.add(1);
-        }
-
-        if data_len < idx_length && resize {
-            // update next pointer
-            *(pos.add(data_len) as *mut usize) = *idx_next_ptr;
-            // update data length
-            *(pos.sub(8) as *mut usize) = data_len;
-        }
-    }
-
-    pub unsafe fn search(idx: usize) -> (usize, *mut u8) {
-
-        let mut current_idx = 1;
-
-        let mut pos = *(Self::BOTTOM_ADDRESS as *mut usize) as *mut u8;
-        let mut idx_length = *(pos as *mut usize);
-        let mut idx_ptr = pos.add(8);
-
-        while current_idx < idx {
-
-            if pos.is_null() {
-                return (0, null_mut());
-            }
-
-            let offset_ptr = pos.add(idx_length as usize + 8);
-
-            pos = *(offset_ptr as *const usize) as *mut u8;
-
-            idx_length = *(pos as *mut usize);
-            idx_ptr = pos.add(8);
-
-            current_idx += 1;
-        };
-
-        (idx_length, idx_ptr)
-        
-    }
-
-}
-
-struct SologAllocator;
-
-impl SologAllocator {
-    const BOTTOM_ADDRESS: usize = HEAP_START_ADDRESS as usize + size_of::<*mut u8>();
-    const TOP_ADDRESS: usize = HEAP_START_ADDRESS as usize + HEAP_LENGTH;
-    const POS_PTR: *mut usize = HEAP_START_ADDRESS as usize as *mut usize;
-}
-
-unsafe impl std::alloc::GlobalAlloc for SologAllocator {
-
-    #[inline]
-    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
-
-        let mut pos = *Self::POS_PTR;
-        if pos == 0 {
-            pos = Self::BOTTOM_ADDRESS;
-        }
-
-        // 8 bytes current pointer
-        *(pos as *mut usize) = pos.saturating_add(8);
-        pos = pos.saturating_add(8);
-
-        // 8 bytes data length
-        let layout_size = layout.size();
-        *(pos as *mut usize) = layout_size;
-        pos = pos.saturating_add(8);
-
-        // x bytes data
-        let return_pos = pos as *mut u8;
-        pos = pos.saturating_add(layout_size as usize);
-
-
-        let next_ptr_pos = pos;
-
-        if next_ptr_pos > Self::TOP_ADDRESS {
-            return null_mut();
-        }
-
-        *Self::POS_PTR = pos;
-        return_pos
-    }
+#[cfg(target_os = "solana")]
+use crate::heap;
 
-    #[inline]
-    unsafe fn dealloc(&self, _: *mut u8, _: Layout) {
-        // no deallocation :>
-    }
-}
+#[cfg(not(feature = "no-entrypoint"))]
+#[cfg(not(feature = "free-list-heap"))]
+#[cfg(target_os = "solana")]
+#[global_allocator]
+static A: heap::SologAllocator = heap::SologAllocator::new(heap::HeapRegion::SBF);
 
 #[cfg(not(feature = "no-entrypoint"))]
+#[cfg(feature = "free-list-heap")]
 #[cfg(target_os = "solana")]
 #[global_allocator]
-static A: SologAllocator = SologAllocator;
+static A: heap::FreeListAllocator = heap::FreeListAllocator::new(heap::HeapRegion::SBF);
 
+#[cfg(not(feature = "no-entrypoint"))]
 solana_program::entrypoint!(process_instruction);
-fn process_instruction(
+
+pub fn process_instruction(
     program_id: &Pubkey,
     accounts: &[AccountInfo],
     instruction_data: &[u8],
@@ -152,7 +44,101 @@ fn process_instruction(
         },
         SologInstruction::EditComment { content } => {
             instruction::edit_comment(program_id, accounts, content)?
+        },
+        SologInstruction::PinComment => {
+            instruction::pin_comment(program_id, accounts)?
+        },
+        SologInstruction::UnpinComment => {
+            instruction::unpin_comment(program_id, accounts)?
+        },
+        SologInstruction::LockComments { locked } => {
+            instruction::lock_comments(program_id, accounts, locked)?
+        },
+        SologInstruction::BlockCommenter => {
+            instruction::block_commenter(program_id, accounts)?
+        },
+        SologInstruction::UnblockCommenter => {
+            instruction::unblock_commenter(program_id, accounts)?
+        },
+        SologInstruction::InitConfig {
+            comment_slot_window,
+            flag_threshold,
+        } => {
+            instruction::init_config(program_id, accounts, comment_slot_window, flag_threshold)?
+        },
+        SologInstruction::UpdateConfig {
+            comment_slot_window,
+            flag_threshold,
+        } => {
+            instruction::update_config(program_id, accounts, comment_slot_window, flag_threshold)?
+        },
+        SologInstruction::SetPostPrice { price, gated_content } => {
+            instruction::set_post_price(program_id, accounts, price, gated_content)?
+        },
+        SologInstruction::PurchaseAccess => {
+            instruction::purchase_access(program_id, accounts)?
+        },
+        SologInstruction::Follow => {
+            instruction::follow(program_id, accounts)?
+        },
+        SologInstruction::Unfollow => {
+            instruction::unfollow(program_id, accounts)?
+        },
+        SologInstruction::SetApprovalThreshold { threshold, require_author } => {
+            instruction::set_approval_threshold(program_id, accounts, threshold, require_author)?
+        },
+        SologInstruction::ProposeEdit { content } => {
+            instruction::propose_edit(program_id, accounts, content)?
+        },
+        SologInstruction::ApproveProposal => {
+            instruction::approve_proposal(program_id, accounts)?
+        },
+        SologInstruction::ExecuteProposal => {
+            instruction::execute_proposal(program_id, accounts)?
+        },
+        SologInstruction::GetPostSummary => {
+            instruction::get_post_summary(program_id, accounts)?
+        },
+        SologInstruction::GetComment => {
+            instruction::get_comment(program_id, accounts)?
+        },
+        SologInstruction::React { kind } => {
+            instruction::react(program_id, accounts, kind)?
+        },
+        SologInstruction::CreateBounty { amount, expires_at } => {
+            instruction::create_bounty(program_id, accounts, amount, expires_at)?
+        },
+        SologInstruction::AwardBounty => {
+            instruction::award_bounty(program_id, accounts)?
+        },
+        SologInstruction::ReclaimBounty => {
+            instruction::reclaim_bounty(program_id, accounts)?
+        },
+        SologInstruction::Repost { title, content } => {
+            instruction::repost(program_id, accounts, title, content)?
+        },
+        SologInstruction::Flag => {
+            instruction::flag(program_id, accounts)?
+        },
+        SologInstruction::Unhide => {
+            instruction::unhide(program_id, accounts)?
+        },
+        SologInstruction::SetPostExpiry { expires_at } => {
+            instruction::set_post_expiry(program_id, accounts, expires_at)?
+        },
+        SologInstruction::CloseExpired => {
+            instruction::close_expired(program_id, accounts)?
+        },
+        SologInstruction::SetModerator { moderator } => {
+            instruction::set_moderator(program_id, accounts, moderator)?
+        },
+        SologInstruction::CancelProposal => {
+            instruction::cancel_proposal(program_id, accounts)?
         }
     };
+
+    #[cfg(all(feature = "heap-stats", target_os = "solana"))]
+    unsafe { heap::HeapStats::read(heap::HeapRegion::SBF) }.log(heap::HeapRegion::SBF);
+
     Ok(())
 }
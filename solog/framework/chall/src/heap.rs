@@ -0,0 +1,940 @@
+use {
+    solana_program::entrypoint::{HEAP_LENGTH, HEAP_START_ADDRESS},
+    std::{
+        alloc::Layout,
+        mem::{size_of, size_of_val},
+        ptr::null_mut,
+    },
+};
+
+#[cfg(any(feature = "dev-tools", feature = "heap-canary", feature = "heap-stats"))]
+use solana_program::msg;
+
+#[cfg(feature = "heap-canary")]
+use solana_program::{clock::Clock, hash::hashv, sysvar::Sysvar};
+
+#[cfg(all(feature = "free-list-heap", feature = "heap-canary"))]
+compile_error!("heap-canary is only supported by the bump allocator");
+
+// Allocator state at the start of the heap: the bump position, followed by
+// the free list head for the free-list allocator, the last chunk and the
+// canary secret for canary checks and the usage statistics, each only when
+// its feature is enabled
+const FREE_HEAD_OFFSET: usize = size_of::<*mut u8>();
+const LAST_CHUNK_OFFSET: usize =
+    FREE_HEAD_OFFSET + size_of::<usize>() * cfg!(feature = "free-list-heap") as usize;
+const CANARY_SECRET_OFFSET: usize =
+    LAST_CHUNK_OFFSET + size_of::<usize>() * cfg!(feature = "heap-canary") as usize;
+const HEAP_STATS_OFFSET: usize =
+    CANARY_SECRET_OFFSET + size_of::<usize>() * cfg!(feature = "heap-canary") as usize;
+
+#[cfg(feature = "heap-stats")]
+const HEAP_STATS_LENGTH: usize = size_of::<HeapStats>();
+#[cfg(not(feature = "heap-stats"))]
+const HEAP_STATS_LENGTH: usize = 0;
+
+/// Bytes reserved at the start of the heap for allocator state
+const HEAP_HEADER_LENGTH: usize = HEAP_STATS_OFFSET + HEAP_STATS_LENGTH;
+
+/// Memory region managed by the allocators, the SBF heap on chain or a
+/// buffer mimicking it on the host
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HeapRegion {
+    start: usize,
+    length: usize,
+}
+
+impl HeapRegion {
+    /// The heap of the SBF virtual machine
+    pub const SBF: HeapRegion = HeapRegion::new(HEAP_START_ADDRESS as usize, HEAP_LENGTH);
+
+    pub const fn new(start: usize, length: usize) -> Self {
+        Self { start, length }
+    }
+
+    /// Region over a host buffer, which like the SBF heap must start zeroed
+    ///
+    /// # Safety
+    ///
+    /// The buffer must outlive every use of the region and of the pointers
+    /// handed out by allocators over it.
+    pub unsafe fn from_buffer(buffer: &mut [u64]) -> Self {
+        Self::new(buffer.as_mut_ptr() as usize, size_of_val(buffer))
+    }
+
+    pub const fn start(&self) -> usize {
+        self.start
+    }
+
+    pub const fn length(&self) -> usize {
+        self.length
+    }
+
+    /// Address of the first chunk, right after the allocator state
+    pub const fn bottom(&self) -> usize {
+        self.start + HEAP_HEADER_LENGTH
+    }
+
+    pub const fn top(&self) -> usize {
+        self.start + self.length
+    }
+
+    /// Address of the bump position word
+    pub const fn pos_ptr(&self) -> *mut usize {
+        self.start as *mut usize
+    }
+
+    #[cfg(feature = "free-list-heap")]
+    const fn free_head_ptr(&self) -> *mut usize {
+        (self.start + FREE_HEAD_OFFSET) as *mut usize
+    }
+
+    #[cfg(feature = "heap-canary")]
+    const fn last_chunk_ptr(&self) -> *mut usize {
+        (self.start + LAST_CHUNK_OFFSET) as *mut usize
+    }
+
+    #[cfg(feature = "heap-canary")]
+    const fn canary_secret_ptr(&self) -> *mut usize {
+        (self.start + CANARY_SECRET_OFFSET) as *mut usize
+    }
+}
+
+/// Heap usage of the current transaction, kept in the heap header
+#[cfg(feature = "heap-stats")]
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct HeapStats {
+    /// bytes currently handed out
+    pub allocated: usize,
+    /// number of allocations made
+    pub allocations: usize,
+    /// highest heap offset ever used, header included
+    pub high_water_mark: usize,
+}
+
+#[cfg(feature = "heap-stats")]
+impl HeapStats {
+    /// Read the statistics of the heap
+    ///
+    /// # Safety
+    ///
+    /// The region must be valid for reads.
+    pub unsafe fn read(heap: HeapRegion) -> Self {
+        Self::ptr(heap).read()
+    }
+
+    #[inline]
+    fn ptr(heap: HeapRegion) -> *mut HeapStats {
+        (heap.start + HEAP_STATS_OFFSET) as *mut HeapStats
+    }
+
+    #[inline]
+    unsafe fn record_alloc(heap: HeapRegion, size: usize, top: usize) {
+        let stats = &mut *Self::ptr(heap);
+        stats.allocated = stats.allocated.saturating_add(size);
+        stats.allocations = stats.allocations.saturating_add(1);
+        stats.high_water_mark = stats.high_water_mark.max(top.saturating_sub(heap.start));
+    }
+
+    #[cfg(feature = "free-list-heap")]
+    #[inline]
+    unsafe fn record_dealloc(heap: HeapRegion, size: usize) {
+        let stats = &mut *Self::ptr(heap);
+        stats.allocated = stats.allocated.saturating_sub(size);
+    }
+
+    pub fn log(&self, heap: HeapRegion) {
+        msg!(
+            "Heap: {} bytes in use, {} allocations, high-water mark {}/{}",
+            self.allocated,
+            self.allocations,
+            self.high_water_mark,
+            heap.length
+        );
+    }
+}
+
+/// Bytes of the canary word stored right before the length of every chunk
+const CHUNK_CANARY_LENGTH: usize = size_of::<usize>() * cfg!(feature = "heap-canary") as usize;
+
+/// Salt of the canary secret
+#[cfg(feature = "heap-canary")]
+const CHUNK_CANARY: usize = 0x5010_6ca1_a2ed_c0de;
+
+/// Secret of the canaries of one invocation, hashed from the clock when the
+/// heap is first used and kept in the heap header, which the dev tools never
+/// hand out, so that canaries can not be forged from dumped chunks
+#[cfg(feature = "heap-canary")]
+fn canary_secret() -> usize {
+    let clock = Clock::get().unwrap_or_default();
+    let hash = hashv(&[
+        &CHUNK_CANARY.to_le_bytes(),
+        &clock.slot.to_le_bytes(),
+        &clock.unix_timestamp.to_le_bytes(),
+    ]);
+
+    let mut secret = [0; size_of::<usize>()];
+    secret.copy_from_slice(&hash.as_ref()[..size_of::<usize>()]);
+    usize::from_le_bytes(secret)
+}
+
+/// Canary of the chunk starting with the pointer word at `link`, mixing in
+/// the addresses of the pointer and length words and the length so that a
+/// rewritten pointer or length is noticed
+#[cfg(feature = "heap-canary")]
+#[inline]
+fn chunk_canary(secret: usize, link: usize, length_pos: usize, length: usize) -> usize {
+    secret ^ link.rotate_left(16) ^ length_pos ^ length.rotate_left(32)
+}
+
+/// Whether the chunk starting with the pointer word at `link` is intact: the
+/// pointer refers to a length word past the header, within the heap, and the
+/// canary right before it matches
+///
+/// # Safety
+///
+/// `link` must be readable, the words it points to are only read once the
+/// pointer has been checked.
+#[cfg(feature = "heap-canary")]
+#[inline]
+unsafe fn is_chunk_intact(heap: HeapRegion, link: usize) -> bool {
+    let length_pos = (link as *const usize).read_unaligned();
+    let data_pos = length_pos.wrapping_add(8);
+
+    // resized chunks leave a gap between the data and the next header, so
+    // only the canary ties the pointer to its chunk
+    let in_header = match link.checked_add(16 + CHUNK_CANARY_LENGTH) {
+        Some(header_end) => header_end <= data_pos && data_pos <= heap.top(),
+        None => false,
+    };
+
+    in_header && {
+        let length_ptr = length_pos as *const usize;
+        length_ptr.sub(1).read_unaligned()
+            == chunk_canary(
+                *heap.canary_secret_ptr(),
+                link,
+                length_pos,
+                length_ptr.read_unaligned(),
+            )
+    }
+}
+
+/// Write the canary of the chunk starting with the pointer word at `link`
+///
+/// # Safety
+///
+/// The pointer and length words of the chunk must be in the heap.
+#[cfg(feature = "heap-canary")]
+#[inline]
+unsafe fn write_canary(heap: HeapRegion, link: usize) {
+    let length_pos = (link as *const usize).read_unaligned();
+    let length = (length_pos as *const usize).read_unaligned();
+    ((length_pos - 8) as *mut usize)
+        .write_unaligned(chunk_canary(*heap.canary_secret_ptr(), link, length_pos, length));
+}
+
+/// Abort the transaction if the chunk starting at `link` was overwritten
+#[cfg(feature = "heap-canary")]
+#[inline]
+unsafe fn check_chunk(heap: HeapRegion, link: usize) {
+    if !is_chunk_intact(heap, link) {
+        msg!("Heap corruption detected");
+        std::process::abort();
+    }
+}
+
+#[cfg(feature = "dev-tools")]
+pub struct AllocatorHandler {
+    heap: HeapRegion,
+}
+
+#[cfg(feature = "dev-tools")]
+impl AllocatorHandler {
+    pub const SBF: AllocatorHandler = AllocatorHandler::new(HeapRegion::SBF);
+
+    pub const fn new(heap: HeapRegion) -> Self {
+        Self { heap }
+    }
+
+    /// Write `data` into the chunk whose data starts at `pos`, optionally
+    /// shrinking the chunk to it
+    ///
+    /// # Safety
+    ///
+    /// `pos` must be the data of a chunk of the heap, as returned by
+    /// [`AllocatorHandler::search`].
+    pub unsafe fn edit(&self, pos: *mut u8, data: &[u8], resize: bool) {
+
+        let idx_length = (pos.sub(8) as *const usize).read_unaligned();
+        let mut idx_data_ptr = pos;
+        let idx_next_ptr = pos.add(idx_length) as *mut usize;
+        let data_len = data.len();
+
+        if idx_length > self.heap.length {
+            msg!("Data length is too long");
+            return;
+        }
+
+        if idx_length == 0 {
+            msg!("Data length is 0");
+            return;
+        }
+
+        if data_len > idx_length + 8 || data_len > 40 {
+            msg!("Data length is too long");
+            return;
+        }
+
+        // the canary of the chunk covers its length and the canary of the
+        // next one the address of its pointer word, both change on resize
+        #[cfg(feature = "heap-canary")]
+        let link = if data_len < idx_length && resize {
+            let link = match self.link_of(pos) {
+                Some(link) => link,
+                None => {
+                    msg!("Chunk not found");
+                    return;
+                }
+            };
+            if idx_next_ptr.read_unaligned() != 0 {
+                check_chunk(self.heap, idx_next_ptr as usize);
+            }
+            link
+        } else {
+            0
+        };
+
+        // write data to idx_data_ptr
+        for byte in data {
+            idx_data_ptr.write_volatile(*byte);
+            idx_data_ptr = idx_data_ptr.add(1);
+        }
+
+        if data_len < idx_length && resize {
+            // update next pointer
+            (pos.add(data_len) as *mut usize).write_unaligned(idx_next_ptr.read_unaligned());
+            // update data length
+            (pos.sub(8) as *mut usize).write_unaligned(data_len);
+
+            #[cfg(feature = "heap-canary")]
+            {
+                write_canary(self.heap, link);
+
+                let next_link = pos.add(data_len) as usize;
+                if (next_link as *const usize).read_unaligned() != 0 {
+                    write_canary(self.heap, next_link);
+                    if *self.heap.last_chunk_ptr() == idx_next_ptr as usize {
+                        *self.heap.last_chunk_ptr() = next_link;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Address of the pointer word of the chunk whose data starts at `pos`
+    ///
+    /// # Safety
+    ///
+    /// The heap must hold at least one chunk.
+    #[cfg(feature = "heap-canary")]
+    unsafe fn link_of(&self, pos: *mut u8) -> Option<usize> {
+        let mut link = self.heap.bottom();
+
+        loop {
+            check_chunk(self.heap, link);
+            let length_pos = (link as *const usize).read_unaligned();
+            if length_pos + 8 == pos as usize {
+                return Some(link);
+            }
+
+            link = length_pos + 8 + (length_pos as *const usize).read_unaligned();
+            if (link as *const usize).read_unaligned() == 0 {
+                return None;
+            }
+        }
+    }
+
+    /// Length and data of the `idx`th chunk of the heap, counting from 1,
+    /// or a null pointer past the last chunk
+    ///
+    /// # Safety
+    ///
+    /// The heap must hold at least one chunk.
+    pub unsafe fn search(&self, idx: usize) -> (usize, *mut u8) {
+
+        let mut current_idx = 1;
+
+        let mut pos = *(self.heap.bottom() as *mut usize) as *mut u8;
+        #[cfg(feature = "heap-canary")]
+        check_chunk(self.heap, self.heap.bottom());
+        let mut idx_length = (pos as *const usize).read_unaligned();
+        let mut idx_ptr = pos.add(8);
+
+        while current_idx < idx {
+
+            let offset_ptr = pos.add(idx_length + 8);
+
+            pos = (offset_ptr as *const usize).read_unaligned() as *mut u8;
+
+            if pos.is_null() {
+                return (0, null_mut());
+            }
+
+            #[cfg(feature = "heap-canary")]
+            check_chunk(self.heap, offset_ptr as usize);
+
+            idx_length = (pos as *const usize).read_unaligned();
+            idx_ptr = pos.add(8);
+
+            current_idx += 1;
+        };
+
+        (idx_length, idx_ptr)
+
+    }
+
+}
+
+pub struct SologAllocator {
+    heap: HeapRegion,
+}
+
+impl SologAllocator {
+    pub const fn new(heap: HeapRegion) -> Self {
+        Self { heap }
+    }
+}
+
+unsafe impl std::alloc::GlobalAlloc for SologAllocator {
+
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+
+        let mut pos = *self.heap.pos_ptr();
+        if pos == 0 {
+            pos = self.heap.bottom();
+            #[cfg(feature = "heap-canary")]
+            {
+                *self.heap.canary_secret_ptr() = canary_secret();
+            }
+        }
+
+        // the previous chunk must be intact before growing past it
+        #[cfg(feature = "heap-canary")]
+        if *self.heap.last_chunk_ptr() != 0 {
+            check_chunk(self.heap, *self.heap.last_chunk_ptr());
+        }
+
+        // padding for the alignment and the canary go between the pointer and
+        // the length, the pointer always refers to the length right before the data
+        let data_pos = match pos.checked_add(16 + CHUNK_CANARY_LENGTH + layout.align() - 1) {
+            Some(data_pos) => data_pos & !(layout.align() - 1),
+            None => return null_mut(),
+        };
+
+        // nothing is written unless the whole chunk fits in the heap
+        let layout_size = layout.size();
+        let next_ptr_pos = match data_pos.checked_add(layout_size) {
+            Some(next_ptr_pos) if next_ptr_pos <= self.heap.top() => next_ptr_pos,
+            _ => return null_mut(),
+        };
+
+        // 8 bytes current pointer, chunks only follow the alignment of
+        // their data so the header words may be unaligned
+        #[cfg(feature = "heap-canary")]
+        let link = pos;
+        (pos as *mut usize).write_unaligned(data_pos - 8);
+
+        // 8 bytes data length
+        ((data_pos - 8) as *mut usize).write_unaligned(layout_size);
+
+        // x bytes data
+        let return_pos = data_pos as *mut u8;
+        pos = next_ptr_pos;
+
+        #[cfg(feature = "heap-canary")]
+        {
+            write_canary(self.heap, link);
+            *self.heap.last_chunk_ptr() = link;
+        }
+
+        #[cfg(feature = "heap-stats")]
+        HeapStats::record_alloc(self.heap, layout_size, pos);
+
+        *self.heap.pos_ptr() = pos;
+        return_pos
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, _: *mut u8, _: Layout) {
+        // no deallocation :>
+    }
+}
+
+/// Allocator reusing freed chunks, kept in an address ordered free list so
+/// that neighbouring free chunks can be coalesced.
+///
+/// Chunks keep the layout of [`SologAllocator`] (pointer word, length word,
+/// data) so that the chunk walk of the dev tools still works, free chunks
+/// store the address of the next free chunk in their first data word.
+///
+/// Chunks are word aligned, layouts with a larger alignment are always bump
+/// allocated and the padding in front of them is released as a free chunk.
+#[cfg(feature = "free-list-heap")]
+pub struct FreeListAllocator {
+    heap: HeapRegion,
+}
+
+#[cfg(feature = "free-list-heap")]
+impl FreeListAllocator {
+    const CHUNK_HEADER_LENGTH: usize = 2 * size_of::<usize>();
+    const MIN_CHUNK_LENGTH: usize = size_of::<usize>();
+    const CHUNK_ALIGN: usize = size_of::<usize>();
+
+    pub const fn new(heap: HeapRegion) -> Self {
+        Self { heap }
+    }
+
+    #[inline]
+    unsafe fn chunk_length(chunk: usize) -> usize {
+        *((chunk + 8) as *const usize)
+    }
+
+    #[inline]
+    unsafe fn set_chunk_length(chunk: usize, length: usize) {
+        *((chunk + 8) as *mut usize) = length;
+    }
+
+    #[inline]
+    unsafe fn next_free(chunk: usize) -> usize {
+        *((chunk + Self::CHUNK_HEADER_LENGTH) as *const usize)
+    }
+
+    #[inline]
+    unsafe fn set_next_free(chunk: usize, next: usize) {
+        *((chunk + Self::CHUNK_HEADER_LENGTH) as *mut usize) = next;
+    }
+
+    #[inline]
+    unsafe fn write_header(chunk: usize, length: usize) {
+        *(chunk as *mut usize) = chunk + 8;
+        Self::set_chunk_length(chunk, length);
+    }
+
+    #[inline]
+    unsafe fn chunk_end(chunk: usize) -> usize {
+        chunk + Self::CHUNK_HEADER_LENGTH + Self::chunk_length(chunk)
+    }
+
+    /// Take the first free chunk large enough for `size`, splitting off the rest
+    unsafe fn alloc_free(&self, size: usize) -> Option<usize> {
+        let free_head_ptr = self.heap.free_head_ptr();
+        let mut prev = 0;
+        let mut chunk = *free_head_ptr;
+
+        while chunk != 0 {
+            let length = Self::chunk_length(chunk);
+            let next = Self::next_free(chunk);
+
+            if length >= size {
+                let replacement =
+                    if length - size >= Self::CHUNK_HEADER_LENGTH + Self::MIN_CHUNK_LENGTH {
+                        let rest = chunk + Self::CHUNK_HEADER_LENGTH + size;
+                        Self::write_header(rest, length - size - Self::CHUNK_HEADER_LENGTH);
+                        Self::set_next_free(rest, next);
+                        Self::set_chunk_length(chunk, size);
+                        rest
+                    } else {
+                        next
+                    };
+
+                if prev == 0 {
+                    *free_head_ptr = replacement;
+                } else {
+                    Self::set_next_free(prev, replacement);
+                }
+                return Some(chunk);
+            }
+
+            prev = chunk;
+            chunk = next;
+        }
+
+        None
+    }
+
+    /// Insert `chunk` into the free list, merging it with free neighbours
+    unsafe fn release(&self, chunk: usize) {
+        let free_head_ptr = self.heap.free_head_ptr();
+        let mut prev = 0;
+        let mut next = *free_head_ptr;
+        while next != 0 && next < chunk {
+            prev = next;
+            next = Self::next_free(next);
+        }
+
+        Self::set_next_free(chunk, next);
+
+        // merge with the following free chunk
+        if next != 0 && Self::chunk_end(chunk) == next {
+            Self::set_chunk_length(
+                chunk,
+                Self::chunk_length(chunk) + Self::CHUNK_HEADER_LENGTH + Self::chunk_length(next),
+            );
+            Self::set_next_free(chunk, Self::next_free(next));
+        }
+
+        if prev == 0 {
+            *free_head_ptr = chunk;
+        } else if Self::chunk_end(prev) == chunk {
+            // merge into the preceding free chunk
+            Self::set_chunk_length(
+                prev,
+                Self::chunk_length(prev) + Self::CHUNK_HEADER_LENGTH + Self::chunk_length(chunk),
+            );
+            Self::set_next_free(prev, Self::next_free(chunk));
+        } else {
+            Self::set_next_free(prev, chunk);
+        }
+    }
+}
+
+#[cfg(feature = "free-list-heap")]
+unsafe impl std::alloc::GlobalAlloc for FreeListAllocator {
+
+    #[inline]
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let align = layout.align();
+        let size = layout
+            .size()
+            .max(Self::MIN_CHUNK_LENGTH)
+            .saturating_add(Self::CHUNK_ALIGN - 1)
+            & !(Self::CHUNK_ALIGN - 1);
+
+        // free chunks are only word aligned
+        if align <= Self::CHUNK_ALIGN {
+            if let Some(chunk) = self.alloc_free(size) {
+                #[cfg(feature = "heap-stats")]
+                HeapStats::record_alloc(self.heap, Self::chunk_length(chunk), Self::chunk_end(chunk));
+                return (chunk + Self::CHUNK_HEADER_LENGTH) as *mut u8;
+            }
+        }
+
+        let mut pos = *self.heap.pos_ptr();
+        if pos == 0 {
+            pos = self.heap.bottom();
+        }
+
+        let mut chunk = pos;
+        if align > Self::CHUNK_ALIGN {
+            chunk = match pos.checked_add(Self::CHUNK_HEADER_LENGTH + align - 1) {
+                Some(data) => (data & !(align - 1)) - Self::CHUNK_HEADER_LENGTH,
+                None => return null_mut(),
+            };
+            // the padding must be able to hold a free chunk of its own
+            if chunk != pos && chunk - pos < Self::CHUNK_HEADER_LENGTH + Self::MIN_CHUNK_LENGTH {
+                chunk = chunk.saturating_add(align);
+            }
+        }
+
+        let end = chunk
+            .saturating_add(Self::CHUNK_HEADER_LENGTH)
+            .saturating_add(size);
+        if end > self.heap.top() {
+            return null_mut();
+        }
+
+        if chunk != pos {
+            Self::write_header(pos, chunk - pos - Self::CHUNK_HEADER_LENGTH);
+            self.release(pos);
+        }
+
+        Self::write_header(chunk, size);
+        *self.heap.pos_ptr() = end;
+
+        #[cfg(feature = "heap-stats")]
+        HeapStats::record_alloc(self.heap, size, end);
+
+        (chunk + Self::CHUNK_HEADER_LENGTH) as *mut u8
+    }
+
+    #[inline]
+    unsafe fn dealloc(&self, ptr: *mut u8, _: Layout) {
+        let chunk = ptr as usize - Self::CHUNK_HEADER_LENGTH;
+
+        #[cfg(feature = "heap-stats")]
+        HeapStats::record_dealloc(self.heap, Self::chunk_length(chunk));
+
+        self.release(chunk);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::alloc::GlobalAlloc;
+
+    /// Zeroed host buffer to run the allocators over
+    fn arena(words: usize) -> Vec<u64> {
+        vec![0; words]
+    }
+
+    /// Address of the length word of the chunk handed out at `ptr`
+    fn length_pos(ptr: *mut u8) -> usize {
+        ptr as usize - size_of::<usize>()
+    }
+
+    #[repr(align(64))]
+    struct CacheLine([u8; 64]);
+
+    #[test]
+    fn bump_chunks_follow_the_alignment_of_their_data() {
+        let mut buffer = arena(128);
+        let heap = unsafe { HeapRegion::from_buffer(&mut buffer) };
+        let allocator = SologAllocator::new(heap);
+
+        unsafe {
+            allocator.alloc(Layout::new::<u8>());
+
+            for layout in [Layout::new::<u128>(), Layout::new::<CacheLine>()] {
+                let chunk = *heap.pos_ptr() as *const usize;
+                let ptr = allocator.alloc(layout);
+                assert!(!ptr.is_null());
+                assert_eq!(ptr as usize % layout.align(), 0);
+
+                // the pointer word of the chunk refers to the length right
+                // before the data
+                assert_eq!(chunk.read_unaligned(), length_pos(ptr));
+                assert_eq!((length_pos(ptr) as *const usize).read_unaligned(), layout.size());
+                assert_eq!(*heap.pos_ptr(), ptr as usize + layout.size());
+            }
+        }
+    }
+
+    #[test]
+    fn failed_allocations_write_nothing() {
+        const GUARD: u64 = 0x6a75_6172_6421_6421;
+        const PAGE: usize = 4096;
+
+        // the region sits right before a page boundary of a larger buffer so
+        // that a page aligned chunk can only start past its end
+        let mut buffer = vec![GUARD; 3 * PAGE / 8];
+        let page = (buffer.as_ptr() as usize + PAGE - 1) & !(PAGE - 1);
+        let start = page + PAGE - 256;
+        let words = (start - buffer.as_ptr() as usize) / 8;
+        buffer[words..words + 16].fill(0);
+        let heap = HeapRegion::new(start, 128);
+        let allocator = SologAllocator::new(heap);
+
+        unsafe {
+            let layout = Layout::from_size_align(8, PAGE).unwrap();
+            assert!(allocator.alloc(layout).is_null());
+            assert!(allocator.alloc(Layout::from_size_align(256, 8).unwrap()).is_null());
+            assert_eq!(*heap.pos_ptr(), 0);
+        }
+
+        for (index, word) in buffer.iter().enumerate() {
+            if !(words..words + 16).contains(&index) {
+                assert_eq!(*word, GUARD, "word {index} past the heap was written");
+            }
+        }
+    }
+
+    #[cfg(feature = "dev-tools")]
+    #[test]
+    fn handler_walks_and_edits_bump_chunks() {
+        let mut buffer = arena(128);
+        let heap = unsafe { HeapRegion::from_buffer(&mut buffer) };
+        let allocator = SologAllocator::new(heap);
+        let handler = AllocatorHandler::new(heap);
+
+        unsafe {
+            let chunks = [16, 24, 32].map(|size| {
+                (size, allocator.alloc(Layout::from_size_align(size, 8).unwrap()))
+            });
+
+            for (index, chunk) in chunks.iter().enumerate() {
+                assert_eq!(handler.search(index + 1), *chunk);
+            }
+            assert!(handler.search(chunks.len() + 1).1.is_null());
+
+            let (length, ptr) = chunks[1];
+            handler.edit(ptr, b"solog", false);
+            assert_eq!(std::slice::from_raw_parts(ptr, 5), b"solog");
+            assert_eq!(handler.search(2), (length, ptr));
+
+            // data longer than the chunk and its length word is refused
+            handler.edit(ptr, &[0xff; 40], false);
+            assert_eq!(std::slice::from_raw_parts(ptr, 5), b"solog");
+        }
+    }
+
+    #[cfg(feature = "dev-tools")]
+    #[test]
+    fn handler_resizes_chunks_keeping_the_walk() {
+        let mut buffer = arena(128);
+        let heap = unsafe { HeapRegion::from_buffer(&mut buffer) };
+        let allocator = SologAllocator::new(heap);
+        let handler = AllocatorHandler::new(heap);
+
+        unsafe {
+            let layout = Layout::from_size_align(32, 8).unwrap();
+            let [first, second] = [(); 2].map(|_| allocator.alloc(layout));
+
+            handler.edit(first, b"short", true);
+            assert_eq!(handler.search(1), (5, first));
+            assert_eq!(handler.search(2), (32, second));
+
+            // both canaries were recomputed, so the heap can keep growing
+            let third = allocator.alloc(layout);
+            assert_eq!(handler.search(3), (32, third));
+        }
+    }
+
+    #[cfg(feature = "heap-canary")]
+    #[test]
+    fn canaries_cover_pointer_and_length_words() {
+        let mut buffer = arena(128);
+        let heap = unsafe { HeapRegion::from_buffer(&mut buffer) };
+        let allocator = SologAllocator::new(heap);
+
+        unsafe {
+            allocator.alloc(Layout::new::<u8>());
+            for layout in [Layout::new::<u64>(), Layout::new::<CacheLine>()] {
+                let link = *heap.pos_ptr();
+                let ptr = allocator.alloc(layout);
+                assert!(is_chunk_intact(heap, link));
+
+                // a pointer redirected into the data, or to a forged chunk
+                // past the padding, is noticed before its target is trusted
+                let link_ptr = link as *mut usize;
+                for forged in [length_pos(ptr) + 8, length_pos(ptr) + 64, link] {
+                    link_ptr.write_unaligned(forged);
+                    assert!(!is_chunk_intact(heap, link));
+                }
+                link_ptr.write_unaligned(length_pos(ptr));
+
+                let length_ptr = length_pos(ptr) as *mut usize;
+                length_ptr.write_unaligned(layout.size() + 8);
+                assert!(!is_chunk_intact(heap, link));
+                length_ptr.write_unaligned(layout.size());
+                assert!(is_chunk_intact(heap, link));
+
+                // canaries only hold for the secret of the invocation
+                let secret = *heap.canary_secret_ptr();
+                *heap.canary_secret_ptr() = !secret;
+                assert!(!is_chunk_intact(heap, link));
+                *heap.canary_secret_ptr() = secret;
+            }
+        }
+    }
+
+    #[cfg(feature = "free-list-heap")]
+    mod free_list {
+        use super::*;
+
+        const HEADER: usize = FreeListAllocator::CHUNK_HEADER_LENGTH;
+
+        fn free_chunks(heap: HeapRegion) -> Vec<(usize, usize)> {
+            let mut chunks = Vec::new();
+            unsafe {
+                let mut chunk = *heap.free_head_ptr();
+                while chunk != 0 {
+                    chunks.push((chunk, FreeListAllocator::chunk_length(chunk)));
+                    chunk = FreeListAllocator::next_free(chunk);
+                }
+            }
+            chunks
+        }
+
+        #[test]
+        fn splits_large_free_chunks() {
+            let mut buffer = arena(128);
+            let heap = unsafe { HeapRegion::from_buffer(&mut buffer) };
+            let allocator = FreeListAllocator::new(heap);
+
+            unsafe {
+                let large = allocator.alloc(Layout::from_size_align(64, 8).unwrap());
+                allocator.alloc(Layout::new::<u64>());
+                allocator.dealloc(large, Layout::from_size_align(64, 8).unwrap());
+
+                let small = allocator.alloc(Layout::from_size_align(16, 8).unwrap());
+                assert_eq!(small, large);
+
+                let rest = small as usize + 16;
+                assert_eq!(free_chunks(heap), [(rest, 64 - 16 - HEADER)]);
+
+                let second = allocator.alloc(Layout::from_size_align(32, 8).unwrap());
+                assert_eq!(second as usize, rest + HEADER);
+                assert!(free_chunks(heap).is_empty());
+            }
+        }
+
+        #[test]
+        fn coalesces_with_both_neighbours() {
+            let mut buffer = arena(128);
+            let heap = unsafe { HeapRegion::from_buffer(&mut buffer) };
+            let allocator = FreeListAllocator::new(heap);
+            let layout = Layout::from_size_align(32, 8).unwrap();
+
+            unsafe {
+                let [a, b, c] = [(); 3].map(|_| allocator.alloc(layout));
+                allocator.alloc(layout);
+
+                allocator.dealloc(a, layout);
+                allocator.dealloc(c, layout);
+                assert_eq!(free_chunks(heap).len(), 2);
+
+                allocator.dealloc(b, layout);
+                let chunk = a as usize - HEADER;
+                assert_eq!(free_chunks(heap), [(chunk, 3 * 32 + 2 * HEADER)]);
+
+                let merged = allocator.alloc(Layout::from_size_align(3 * 32 + 2 * HEADER, 8).unwrap());
+                assert_eq!(merged, a);
+            }
+        }
+
+        #[test]
+        fn reuses_freed_chunks() {
+            let mut buffer = arena(64);
+            let heap = unsafe { HeapRegion::from_buffer(&mut buffer) };
+            let allocator = FreeListAllocator::new(heap);
+            let layout = Layout::from_size_align(24, 8).unwrap();
+
+            unsafe {
+                let first = allocator.alloc(layout);
+                let pos = *heap.pos_ptr();
+                allocator.dealloc(first, layout);
+
+                assert_eq!(allocator.alloc(layout), first);
+                assert_eq!(*heap.pos_ptr(), pos);
+            }
+        }
+
+        #[test]
+        fn over_aligned_layouts_release_their_padding() {
+            let mut buffer = arena(128);
+            let heap = unsafe { HeapRegion::from_buffer(&mut buffer) };
+            let allocator = FreeListAllocator::new(heap);
+
+            unsafe {
+                allocator.alloc(Layout::new::<u64>());
+
+                for layout in [Layout::new::<u128>(), Layout::from_size_align(64, 64).unwrap()] {
+                    let ptr = allocator.alloc(layout);
+                    assert!(!ptr.is_null());
+                    assert_eq!(ptr as usize % layout.align(), 0);
+                    assert!(ptr as usize + layout.size() <= heap.top());
+                }
+
+                // the padding is handed out again to word aligned layouts
+                let padding = free_chunks(heap);
+                assert!(!padding.is_empty());
+                let ptr = allocator.alloc(Layout::new::<u64>());
+                assert_eq!(ptr as usize, padding[0].0 + HEADER);
+            }
+        }
+    }
+}
@@ -1,9 +1,11 @@
-use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
 };
 
-use crate::processor::{SologData, MAX_COLLABORATORS};
+use crate::{
+    event::SologEvent,
+    processor::{PostHeader, MAX_COLLABORATORS},
+};
 
 pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
     let [author, collaborator, post_info] = arrayref::array_ref![accounts, 0, 3];
@@ -16,10 +18,10 @@ pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResu
         return Err(ProgramError::InvalidAccountData);
     }
 
-    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
-    let mut post = match post {
-        SologData::Post(post) => post,
-        _ => return Err(ProgramError::InvalidInstructionData),
+    let mut data = post_info.try_borrow_mut_data()?;
+    let post = match PostHeader::from_account_data_mut(&mut data) {
+        Some(post) => post,
+        None => return Err(ProgramError::InvalidInstructionData),
     };
 
     if post.author != *author.key {
@@ -40,6 +42,10 @@ pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResu
     post.collaborators[post.collaborator_count as usize] = *collaborator.key;
     post.collaborator_count += 1;
 
-    SologData::Post(post).serialize(&mut &mut post_info.try_borrow_mut_data()?[..])?;
+    SologEvent::CollaboratorAdded {
+        post: *post_info.key,
+        collaborator: *collaborator.key,
+    }
+    .emit()?;
     Ok(())
 }
@@ -1,18 +1,21 @@
 use borsh::{to_vec, BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo,
+    clock::Clock,
     entrypoint::ProgramResult,
     msg,
-    program::invoke_signed,
     program_error::ProgramError,
     pubkey::Pubkey,
-    rent::Rent,
-    system_instruction,
+    sysvar::Sysvar,
 };
 
 use crate::{
-    instruction::COMMENT_SUFFIX,
-    processor::{Comment, SologData},
+    event::SologEvent,
+    instruction::{
+        check_commenter, create_pda_account, find_comment_address, find_rate_limit_address,
+        load_config, payer_or, COMMENT_SUFFIX, RATE_LIMIT_SUFFIX,
+    },
+    processor::{Comment, RateLimit, SologData},
 };
 
 pub fn instruction(
@@ -20,7 +23,16 @@ pub fn instruction(
     accounts: &[AccountInfo],
     content: Vec<u8>,
 ) -> ProgramResult {
-    let [author, post_info, comment_info, system_program] = arrayref::array_ref![accounts, 0, 4];
+    let [
+        author,
+        post_info,
+        comment_info,
+        system_program,
+        block_list_info,
+        config_info,
+        rate_limit_info,
+    ] = arrayref::array_ref![accounts, 0, 7];
+    let payer = payer_or(accounts, 7, author)?;
     
     if !author.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
@@ -34,15 +46,22 @@ pub fn instruction(
         _ => return Err(ProgramError::InvalidInstructionData),
     };
 
-    let (comment, comment_seed) = Pubkey::find_program_address(
-        &[
-            COMMENT_SUFFIX,
-            &post_info.key.to_bytes()[..16],
-            &author.key.to_bytes()[..16],
-            &post_data.comment_count.to_le_bytes(),
-        ],
+    check_commenter(program_id, post_info, &post_data, block_list_info, author.key)?;
+
+    let comment_slot_window = load_config(program_id, config_info)?
+        .map_or(0, |config| config.comment_slot_window);
+    check_rate_limit(
         program_id,
-    );
+        post_info,
+        author.key,
+        payer,
+        rate_limit_info,
+        system_program,
+        comment_slot_window,
+    )?;
+
+    let (comment, comment_seed) =
+        find_comment_address(program_id, post_info.key, author.key, post_data.comment_count);
     if comment_info.key != &comment {
         return Err(ProgramError::InvalidAccountData);
     }
@@ -53,29 +72,26 @@ pub fn instruction(
     let comment_data = SologData::Comment(Comment {
         claps: 0,
         order: post_data.comment_count,
+        flags: 0,
+        hidden: false,
         author: *author.key,
         content: content.clone(),
+        rent_payer: *payer.key,
     });
 
-    let comment_data_len = to_vec(&comment_data)?.len();
-    let rent = Rent::default().minimum_balance(comment_data_len);
-
-    invoke_signed(
-        &system_instruction::create_account(
-            author.key,
-            comment_info.key,
-            rent,
-            comment_data_len as u64,
-            program_id,
-        ),
-        &[author.clone(), comment_info.clone(), system_program.clone()],
-        &[&[
+    create_pda_account(
+        program_id,
+        payer,
+        comment_info,
+        system_program,
+        to_vec(&comment_data)?.len(),
+        &[
             COMMENT_SUFFIX,
             &post_info.key.to_bytes()[..16],
             &author.key.to_bytes()[..16],
             &post_data.comment_count.to_le_bytes(),
             &[comment_seed],
-        ]],
+        ],
     )?;
 
     post_data.comment_count += 1;
@@ -90,6 +106,79 @@ pub fn instruction(
     let mut comment_info = comment_info.try_borrow_mut_data()?;
     comment_data.serialize(&mut &mut comment_info[..])?;
 
-    msg!("Comment {} on Post {} created: {}", comment_order, post_key, comment_key);
+    SologEvent::CommentAdded {
+        post: post_key,
+        comment: comment_key,
+        author: *author.key,
+        order: comment_order,
+    }
+    .emit()?;
+    Ok(())
+}
+
+/// Reject a comment posted within `comment_slot_window` slots of the author's previous one
+fn check_rate_limit<'a>(
+    program_id: &Pubkey,
+    post_info: &AccountInfo<'a>,
+    author: &Pubkey,
+    payer: &AccountInfo<'a>,
+    rate_limit_info: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    comment_slot_window: u64,
+) -> ProgramResult {
+    let (rate_limit, rate_limit_seed) =
+        find_rate_limit_address(program_id, post_info.key, author);
+    if rate_limit_info.key != &rate_limit {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let current_slot = Clock::get()?.slot;
+
+    // first comment of this author on the post
+    if rate_limit_info.data_is_empty() {
+        let rate_limit_data = SologData::RateLimit(RateLimit {
+            last_comment_slot: current_slot,
+        });
+
+        create_pda_account(
+            program_id,
+            payer,
+            rate_limit_info,
+            system_program,
+            to_vec(&rate_limit_data)?.len(),
+            &[
+                RATE_LIMIT_SUFFIX,
+                &post_info.key.to_bytes(),
+                &author.to_bytes(),
+                &[rate_limit_seed],
+            ],
+        )?;
+
+        rate_limit_data.serialize(&mut &mut rate_limit_info.try_borrow_mut_data()?[..])?;
+        return Ok(());
+    }
+
+    if rate_limit_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let rate_limit_data = SologData::try_from_slice(&rate_limit_info.try_borrow_data()?)?;
+    let mut rate_limit_data = match rate_limit_data {
+        SologData::RateLimit(rate_limit) => rate_limit,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    let next_slot = rate_limit_data
+        .last_comment_slot
+        .saturating_add(comment_slot_window);
+    if current_slot < next_slot {
+        msg!("Commenting too fast, wait until slot {}", next_slot);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    rate_limit_data.last_comment_slot = current_slot;
+    SologData::RateLimit(rate_limit_data)
+        .serialize(&mut &mut rate_limit_info.try_borrow_mut_data()?[..])?;
+
     Ok(())
 }
@@ -0,0 +1,60 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::load_proposal,
+    processor::SologData,
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [approver, post_info, proposal_info] = arrayref::array_ref![accounts, 0, 3];
+
+    if !approver.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !proposal_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let approver_index = match post.approver_index(approver.key) {
+        Some(index) => index,
+        None => {
+            msg!("Only the author or a collaborator can approve edits");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    };
+
+    let mut proposal = load_proposal(program_id, post_info, proposal_info)?;
+
+    if proposal.approvals & (1 << approver_index) != 0 {
+        msg!("Proposal already approved");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    proposal.approvals |= 1 << approver_index;
+    let approvals = proposal.approval_count();
+
+    SologData::Proposal(proposal).serialize(&mut &mut proposal_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::ProposalApproved {
+        proposal: *proposal_info.key,
+        approver: *approver.key,
+        approvals,
+    }
+    .emit()?;
+    Ok(())
+}
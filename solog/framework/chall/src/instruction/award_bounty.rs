@@ -0,0 +1,84 @@
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{close_account, find_comment_address, load_bounty},
+    processor::SologData,
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [author, post_info, comment_info, comment_author, bounty_info] =
+        arrayref::array_ref![accounts, 0, 5];
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !bounty_info.is_writable || !comment_author.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if comment_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // a closed vault is no longer program owned, so a bounty pays out once
+    let bounty = load_bounty(program_id, author, post_info, bounty_info)?;
+
+    if Clock::get()?.unix_timestamp >= bounty.expires_at {
+        msg!("Bounty expired");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let comment = match SologData::try_from_slice(&comment_info.try_borrow_data()?)? {
+        SologData::Comment(comment) => comment,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    let (expected_comment, _) =
+        find_comment_address(program_id, post_info.key, &comment.author, comment.order);
+    if comment_info.key != &expected_comment {
+        msg!("Comment does not belong to this post");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if comment_author.key != &comment.author {
+        msg!("Comment author mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if comment.author == *author.key {
+        msg!("Can not award your own comment");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    **bounty_info.try_borrow_mut_lamports()? = bounty_info
+        .lamports()
+        .checked_sub(bounty.amount)
+        .ok_or(ProgramError::InsufficientFunds)?;
+    **comment_author.try_borrow_mut_lamports()? = comment_author
+        .lamports()
+        .checked_add(bounty.amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    // the rent goes back to the author
+    close_account(bounty_info, author)?;
+
+    SologEvent::BountyAwarded {
+        post: *post_info.key,
+        comment: *comment_info.key,
+        winner: comment.author,
+        amount: bounty.amount,
+    }
+    .emit()?;
+    Ok(())
+}
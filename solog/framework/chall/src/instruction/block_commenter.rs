@@ -0,0 +1,108 @@
+use borsh::{to_vec, BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{create_pda_account, find_block_list_address, payer_or, BLOCK_LIST_SUFFIX},
+    processor::{BlockList, SologData, MAX_BLOCKED_COMMENTERS},
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [author, post_info, commenter, block_list_info, system_program] =
+        arrayref::array_ref![accounts, 0, 5];
+    let payer = payer_or(accounts, 5, author)?;
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !block_list_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if system_program.key != &system_program::id() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if post.author != *author.key {
+        msg!("Author mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if post.author == *commenter.key {
+        msg!("Author can not block themselves");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let (block_list, block_list_seed) = find_block_list_address(program_id, post_info.key);
+    if block_list_info.key != &block_list {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut block_list = if block_list_info.data_is_empty() {
+        let block_list = BlockList {
+            post: *post_info.key,
+            blocked: [Pubkey::default(); MAX_BLOCKED_COMMENTERS],
+            blocked_count: 0,
+        };
+
+        create_pda_account(
+            program_id,
+            payer,
+            block_list_info,
+            system_program,
+            to_vec(&SologData::BlockList(block_list.clone()))?.len(),
+            &[BLOCK_LIST_SUFFIX, &post_info.key.to_bytes(), &[block_list_seed]],
+        )?;
+
+        block_list
+    } else {
+        if block_list_info.owner != program_id {
+            return Err(ProgramError::InvalidAccountData);
+        }
+        match SologData::try_from_slice(&block_list_info.try_borrow_data()?)? {
+            SologData::BlockList(block_list) => block_list,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        }
+    };
+
+    if block_list.is_blocked(commenter.key) {
+        msg!("Commenter already blocked");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if block_list.blocked_count >= MAX_BLOCKED_COMMENTERS as u8 {
+        msg!("Too many blocked commenters");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    block_list.blocked[block_list.blocked_count as usize] = *commenter.key;
+    block_list.blocked_count += 1;
+
+    SologData::BlockList(block_list)
+        .serialize(&mut &mut block_list_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::CommenterBlocked {
+        post: *post_info.key,
+        commenter: *commenter.key,
+    }
+    .emit()?;
+    Ok(())
+}
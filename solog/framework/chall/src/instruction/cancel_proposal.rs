@@ -0,0 +1,53 @@
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{close_account, load_proposal},
+    processor::SologData,
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [canceller, post_info, proposal_info, proposer] = arrayref::array_ref![accounts, 0, 4];
+
+    if !canceller.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !proposal_info.is_writable || !proposer.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let proposal = load_proposal(program_id, post_info, proposal_info)?;
+
+    if proposal.proposer != *proposer.key {
+        msg!("Proposer mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if canceller.key != proposer.key {
+        if post_info.owner != program_id {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let post = match SologData::try_from_slice(&post_info.try_borrow_data()?)? {
+            SologData::Post(post) => post,
+            _ => return Err(ProgramError::InvalidInstructionData),
+        };
+        if post.author != *canceller.key {
+            msg!("Only the proposer or the author can cancel a proposal");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    close_account(proposal_info, proposer)?;
+
+    SologEvent::ProposalCancelled {
+        post: *post_info.key,
+        proposal: *proposal_info.key,
+    }
+    .emit()?;
+    Ok(())
+}
@@ -1,14 +1,17 @@
-use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
+    msg,
     program_error::ProgramError,
     pubkey::Pubkey,
     system_program,
 };
 
+#[cfg(feature = "dev-tools")]
+use crate::dev;
 use crate::{
-    dev, processor::SologData
+    event::SologEvent,
+    processor::{CommentHeader, PostHeader},
 };
 
 pub fn instruction(
@@ -22,6 +25,12 @@ pub fn instruction(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
+    #[cfg(not(feature = "dev-tools"))]
+    if !instruction_data.is_empty() {
+        msg!("Clap data is only accepted with dev tools");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
     if !(post_or_comment_info.owner == program_id) {
         return Err(ProgramError::InvalidAccountData);
     }
@@ -30,20 +39,37 @@ pub fn instruction(
         return Err(ProgramError::InvalidAccountData);
     }
 
-    let data = SologData::try_from_slice(&post_or_comment_info.data.borrow())?;
+    let claps = {
+        let mut data = post_or_comment_info.try_borrow_mut_data()?;
 
-    match data {
-        SologData::Post(mut post) => {
+        if let Some(post) = PostHeader::from_account_data_mut(&mut data) {
+            if post.hidden() {
+                msg!("Post is hidden");
+                return Err(ProgramError::InvalidInstructionData);
+            }
             post.claps += 1;
-            SologData::Post(post).serialize(&mut &mut post_or_comment_info.try_borrow_mut_data()?[..])?;
-        },
-        SologData::Comment(mut comment) => {
+            post.claps
+        } else if let Some(comment) = CommentHeader::from_account_data_mut(&mut data) {
+            if comment.hidden() {
+                msg!("Comment is hidden");
+                return Err(ProgramError::InvalidInstructionData);
+            }
             comment.claps += 1;
-            SologData::Comment(comment).serialize(&mut &mut post_or_comment_info.try_borrow_mut_data()?[..])?;
+            comment.claps
+        } else {
+            return Err(ProgramError::InvalidInstructionData);
         }
+    };
+
+    SologEvent::Clapped {
+        target: *post_or_comment_info.key,
+        viewer: *viewer.key,
+        claps,
     }
+    .emit()?;
 
     // Only for testing
+    #[cfg(feature = "dev-tools")]
     dev::heap_kit(instruction_data)?;
     
     Ok(())
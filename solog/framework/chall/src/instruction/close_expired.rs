@@ -0,0 +1,112 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{close_account, find_bounty_address, find_comment_address, load_bounty},
+    processor::SologData,
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [post_info, author, bounty_info, rent_payer] = arrayref::array_ref![accounts, 0, 4];
+    let comments = accounts[4..].chunks_exact(2);
+
+    if !comments.remainder().is_empty() {
+        msg!("Every comment must come with its rent payer");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    if !post_info.is_writable || post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (bounty, _) = find_bounty_address(program_id, post_info.key);
+    if bounty_info.key != &bounty || !bounty_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let mut post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if !post.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("Post has not expired");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if post.author != *author.key {
+        msg!("Author mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let comments_closed = comments.len() as u8;
+    for pair in comments {
+        let [comment_info, comment_rent_payer] = arrayref::array_ref![pair, 0, 2];
+
+        if comment_info.owner != program_id {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        let comment = match SologData::try_from_slice(&comment_info.try_borrow_data()?)? {
+            SologData::Comment(comment) => comment,
+            _ => return Err(ProgramError::InvalidAccountData),
+        };
+
+        let (expected_comment, _) =
+            find_comment_address(program_id, post_info.key, &comment.author, comment.order);
+        if comment_info.key != &expected_comment {
+            msg!("Comment does not belong to this post");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if comment.rent_payer != *comment_rent_payer.key {
+            msg!("Comment rent payer mismatch");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        close_account(comment_info, comment_rent_payer)?;
+        post.closed_comments += 1;
+    }
+
+    let remaining_comments = post.comment_count - post.closed_comments;
+    if remaining_comments == 0 {
+        // nothing is left to award the bounty to, so it goes back to the author
+        if bounty_info.owner == program_id {
+            let bounty = load_bounty(program_id, author, post_info, bounty_info)?;
+            close_account(bounty_info, author)?;
+
+            SologEvent::BountyReclaimed {
+                post: *post_info.key,
+                amount: bounty.amount,
+            }
+            .emit()?;
+        }
+
+        if post.rent_payer != *rent_payer.key {
+            msg!("Post rent payer mismatch");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        close_account(post_info, rent_payer)?;
+    } else {
+        SologData::Post(post).serialize(&mut &mut post_info.try_borrow_mut_data()?[..])?;
+    }
+
+    SologEvent::ExpiredClosed {
+        post: *post_info.key,
+        comments_closed,
+        remaining_comments,
+    }
+    .emit()?;
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use borsh::{to_vec, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction, system_program,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{create_pda_account, find_bounty_address, payer_or, BOUNTY_SUFFIX},
+    processor::{Bounty, PostHeader, SologData},
+};
+
+pub fn instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    amount: u64,
+    expires_at: i64,
+) -> ProgramResult {
+    let [author, post_info, bounty_info, system_program] = arrayref::array_ref![accounts, 0, 4];
+    let payer = payer_or(accounts, 4, author)?;
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !bounty_info.is_writable || !bounty_info.data_is_empty() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if system_program.key != &system_program::id() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    match PostHeader::from_account_data(&post_info.try_borrow_data()?) {
+        Some(post) if post.author == *author.key => {}
+        Some(_) => {
+            msg!("Author mismatch");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        None => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    if amount == 0 {
+        msg!("Bounty is empty");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if expires_at <= Clock::get()?.unix_timestamp {
+        msg!("Bounty already expired");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let (bounty, bounty_seed) = find_bounty_address(program_id, post_info.key);
+    if bounty_info.key != &bounty {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let bounty_data = SologData::Bounty(Bounty {
+        post: *post_info.key,
+        amount,
+        expires_at,
+    });
+
+    create_pda_account(
+        program_id,
+        payer,
+        bounty_info,
+        system_program,
+        to_vec(&bounty_data)?.len(),
+        &[BOUNTY_SUFFIX, &post_info.key.to_bytes(), &[bounty_seed]],
+    )?;
+
+    // the vault holds the bounty on top of its rent
+    invoke(
+        &system_instruction::transfer(payer.key, bounty_info.key, amount),
+        &[payer.clone(), bounty_info.clone(), system_program.clone()],
+    )?;
+
+    bounty_data.serialize(&mut &mut bounty_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::BountyCreated {
+        post: *post_info.key,
+        amount,
+        expires_at,
+    }
+    .emit()?;
+    Ok(())
+}
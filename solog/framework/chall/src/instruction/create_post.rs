@@ -1,18 +1,17 @@
-use borsh::{to_vec, BorshDeserialize, BorshSerialize};
+use borsh::{to_vec, BorshSerialize};
 use solana_program::{
     account_info::AccountInfo,
     entrypoint::ProgramResult,
     msg,
-    program::invoke_signed,
     program_error::ProgramError,
     pubkey::Pubkey,
-    rent::Rent,
-    system_instruction, system_program,
+    system_program,
 };
 
 use crate::{
-    instruction::POST_SUFFIX,
-    processor::{Post, SologData, MAX_COLLABORATORS},
+    event::SologEvent,
+    instruction::{create_pda_account, find_post_address, payer_or, POST_SUFFIX},
+    processor::{Post, SologData},
 };
 
 pub fn instruction(
@@ -22,6 +21,7 @@ pub fn instruction(
     content: Vec<u8>,
 ) -> ProgramResult {
     let [author, post_info, system_program] = arrayref::array_ref![accounts, 0, 3];
+    let payer = payer_or(accounts, 3, author)?;
 
     if !author.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
@@ -38,10 +38,7 @@ pub fn instruction(
         return Err(ProgramError::InvalidInstructionData);
     }
 
-    let (post, post_seed) = Pubkey::find_program_address(
-        &[POST_SUFFIX, &author.key.to_bytes(), &title],
-        program_id,
-    );
+    let (post, post_seed) = find_post_address(program_id, author.key, &title);
     if post_info.key != &post {
         return Err(ProgramError::InvalidAccountData);
     }
@@ -50,39 +47,29 @@ pub fn instruction(
     }
 
     let post_data = SologData::Post(Post {
-        claps: 0,
-        author: *author.key,
-        collaborator_count: 0,
-        comment_count: 0,
-        collaborators: [Pubkey::try_from_slice(&[0xff; 32]).unwrap(); MAX_COLLABORATORS],
-        title: title.clone(),
-        content: content.clone(),
+        rent_payer: *payer.key,
+        ..Post::new(*author.key, title.clone(), content)
     });
 
-    let post_data_len = to_vec(&post_data)?.len();
-    let rent = Rent::default().minimum_balance(post_data_len);
-
-    invoke_signed(
-        &system_instruction::create_account(
-            author.key,
-            post_info.key,
-            rent,
-            post_data_len as u64,
-            program_id,
-        ),
-        &[author.clone(), post_info.clone(), system_program.clone()],
-        &[&[
-            POST_SUFFIX,
-            &author.key.to_bytes(),
-            &title,
-            &[post_seed],
-        ]],
+    create_pda_account(
+        program_id,
+        payer,
+        post_info,
+        system_program,
+        to_vec(&post_data)?.len(),
+        &[POST_SUFFIX, &author.key.to_bytes(), &title, &[post_seed]],
     )?;
 
-    msg!("Post created at: {}", post_info.key);
+    let post_key = *post_info.key;
 
     let mut post_info = post_info.try_borrow_mut_data()?;
     post_data.serialize(&mut &mut post_info[..])?;
 
+    SologEvent::PostCreated {
+        post: post_key,
+        author: *author.key,
+    }
+    .emit()?;
+
     Ok(())
 }
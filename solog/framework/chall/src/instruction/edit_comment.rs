@@ -10,7 +10,11 @@ use solana_program::{
     system_instruction,
 };
 
-use crate::processor::SologData;
+use crate::{
+    event::SologEvent,
+    instruction::{check_commenter, find_comment_address, payer_or},
+    processor::SologData,
+};
 
 
 pub fn instruction(
@@ -18,7 +22,13 @@ pub fn instruction(
     accounts: &[AccountInfo],
     content: Vec<u8>,
 ) -> ProgramResult {
-    let [author, comment_info, system_program] = arrayref::array_ref![accounts, 0, 3];
+    // the post and its block list are always needed to enforce locks and blocks
+    if accounts.len() < 5 {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let [author, comment_info, system_program, post_info, block_list_info] =
+        arrayref::array_ref![accounts, 0, 5];
+    let payer = payer_or(accounts, 5, author)?;
 
     if !author.is_signer {
         return Err(ProgramError::MissingRequiredSignature);
@@ -39,6 +49,25 @@ pub fn instruction(
         return Err(ProgramError::InvalidAccountData);
     }
 
+    if post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post_data = SologData::try_from_slice(&post_info.data.borrow())?;
+    let post_data = match post_data {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let (comment, _) =
+        find_comment_address(program_id, post_info.key, &comment_data.author, comment_data.order);
+    if comment_info.key != &comment {
+        msg!("Comment does not belong to this post");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    check_commenter(program_id, post_info, &post_data, block_list_info, author.key)?;
+
     comment_data.content = content;
     let comment_data = SologData::Comment(comment_data);
 
@@ -48,13 +77,17 @@ pub fn instruction(
 
 
     invoke(
-        &system_instruction::transfer(author.key, comment_info.key, diff_rent),
-        &[author.clone(), comment_info.clone(), system_program.clone()],
+        &system_instruction::transfer(payer.key, comment_info.key, diff_rent),
+        &[payer.clone(), comment_info.clone(), system_program.clone()],
     )?;
 
     comment_info.realloc(new_comment_data_len, false)?;
     comment_data.serialize(&mut &mut comment_info.try_borrow_mut_data()?[..])?;
 
-    msg!("edit comment success");
+    SologEvent::CommentEdited {
+        comment: *comment_info.key,
+        author: *author.key,
+    }
+    .emit()?;
     Ok(())
 }
@@ -0,0 +1,91 @@
+use borsh::{to_vec, BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{close_account, load_proposal},
+    processor::SologData,
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [executor, post_info, proposal_info, proposer, system_program] =
+        arrayref::array_ref![accounts, 0, 5];
+
+    if !executor.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !post_info.is_writable || post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !proposal_info.is_writable || !proposer.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let mut post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if post.approver_index(executor.key).is_none() {
+        msg!("Only the author or a collaborator can execute edits");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let proposal = load_proposal(program_id, post_info, proposal_info)?;
+
+    if proposal.proposer != *proposer.key {
+        msg!("Proposer mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // the author is approver 0
+    if post.require_author && proposal.approvals & 1 == 0 {
+        msg!("Proposal is not approved by the author");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if proposal.approval_count() < post.approval_threshold {
+        msg!(
+            "Not enough approvals: {}/{}",
+            proposal.approval_count(),
+            post.approval_threshold
+        );
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    post.content = proposal.content;
+    let post = SologData::Post(post);
+
+    let new_post_data_len = to_vec(&post)?.len();
+    let new_rent = Rent::default().minimum_balance(new_post_data_len);
+    let diff_rent = new_rent.saturating_sub(post_info.lamports());
+
+    invoke(
+        &system_instruction::transfer(executor.key, post_info.key, diff_rent),
+        &[executor.clone(), post_info.clone(), system_program.clone()],
+    )?;
+
+    post_info.realloc(new_post_data_len, false)?;
+    post.serialize(&mut &mut post_info.try_borrow_mut_data()?[..])?;
+
+    close_account(proposal_info, proposer)?;
+
+    SologEvent::ProposalExecuted {
+        post: *post_info.key,
+        proposal: *proposal_info.key,
+    }
+    .emit()?;
+    Ok(())
+}
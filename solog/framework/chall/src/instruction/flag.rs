@@ -0,0 +1,101 @@
+use borsh::{to_vec, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{create_pda_account, find_flag_address, load_config, payer_or, FLAG_SUFFIX},
+    processor::{CommentHeader, Flag, PostHeader, SologData},
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [flagger, target_info, flag_info, config_info, system_program] =
+        arrayref::array_ref![accounts, 0, 5];
+    let payer = payer_or(accounts, 5, flagger)?;
+
+    if !flagger.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !target_info.is_writable || !flag_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if target_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if system_program.key != &system_program::id() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (flag, flag_seed) = find_flag_address(program_id, target_info.key, flagger.key);
+    if flag_info.key != &flag {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if !flag_info.data_is_empty() {
+        msg!("Already flagged");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let flag_threshold = load_config(program_id, config_info)?
+        .map_or(0, |config| config.flag_threshold);
+
+    let (flags, hidden) = {
+        let mut data = target_info.try_borrow_mut_data()?;
+
+        if let Some(post) = PostHeader::from_account_data_mut(&mut data) {
+            let flags = post.flags().checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+            post.set_flags(flags);
+            if flag_threshold != 0 && flags >= flag_threshold {
+                post.set_hidden(true);
+            }
+            (flags, post.hidden())
+        } else if let Some(comment) = CommentHeader::from_account_data_mut(&mut data) {
+            let flags = comment.flags().checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+            comment.set_flags(flags);
+            if flag_threshold != 0 && flags >= flag_threshold {
+                comment.set_hidden(true);
+            }
+            (flags, comment.hidden())
+        } else {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    };
+
+    let flag_data = SologData::Flag(Flag {
+        target: *target_info.key,
+        flagger: *flagger.key,
+    });
+
+    create_pda_account(
+        program_id,
+        payer,
+        flag_info,
+        system_program,
+        to_vec(&flag_data)?.len(),
+        &[
+            FLAG_SUFFIX,
+            &target_info.key.to_bytes(),
+            &flagger.key.to_bytes(),
+            &[flag_seed],
+        ],
+    )?;
+
+    flag_data.serialize(&mut &mut flag_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::Flagged {
+        target: *target_info.key,
+        flagger: *flagger.key,
+        flags,
+        hidden,
+    }
+    .emit()?;
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use borsh::{to_vec, BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{
+        create_pda_account, find_follow_address, find_profile_address, payer_or,
+        FOLLOW_SUFFIX, PROFILE_SUFFIX,
+    },
+    processor::{Follow, Profile, SologData},
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [
+        follower,
+        following,
+        follower_profile_info,
+        following_profile_info,
+        follow_info,
+        system_program,
+    ] = arrayref::array_ref![accounts, 0, 6];
+    let payer = payer_or(accounts, 6, follower)?;
+
+    if !follower.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !follower_profile_info.is_writable
+        || !following_profile_info.is_writable
+        || !follow_info.is_writable
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if system_program.key != &system_program::id() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if follower.key == following.key {
+        msg!("Can not follow yourself");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let (follow, follow_seed) = find_follow_address(program_id, follower.key, following.key);
+    if follow_info.key != &follow {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if !follow_info.data_is_empty() {
+        msg!("Already following");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut follower_profile = load_or_create_profile(
+        program_id,
+        payer,
+        follower.key,
+        follower_profile_info,
+        system_program,
+    )?;
+    let mut following_profile = load_or_create_profile(
+        program_id,
+        payer,
+        following.key,
+        following_profile_info,
+        system_program,
+    )?;
+
+    let follow_data = SologData::Follow(Follow {
+        follower: *follower.key,
+        following: *following.key,
+    });
+
+    create_pda_account(
+        program_id,
+        payer,
+        follow_info,
+        system_program,
+        to_vec(&follow_data)?.len(),
+        &[
+            FOLLOW_SUFFIX,
+            &follower.key.to_bytes(),
+            &following.key.to_bytes(),
+            &[follow_seed],
+        ],
+    )?;
+
+    follow_data.serialize(&mut &mut follow_info.try_borrow_mut_data()?[..])?;
+
+    follower_profile.following_count = follower_profile
+        .following_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    following_profile.follower_count = following_profile
+        .follower_count
+        .checked_add(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    SologData::Profile(follower_profile)
+        .serialize(&mut &mut follower_profile_info.try_borrow_mut_data()?[..])?;
+    SologData::Profile(following_profile)
+        .serialize(&mut &mut following_profile_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::Followed {
+        follower: *follower.key,
+        following: *following.key,
+    }
+    .emit()?;
+    Ok(())
+}
+
+/// Load the profile of `owner`, creating it at the expense of `payer` on first use
+fn load_or_create_profile<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    owner: &Pubkey,
+    profile_info: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+) -> Result<Profile, ProgramError> {
+    let (profile, profile_seed) = find_profile_address(program_id, owner);
+    if profile_info.key != &profile {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if profile_info.data_is_empty() {
+        let profile = Profile {
+            owner: *owner,
+            follower_count: 0,
+            following_count: 0,
+        };
+
+        create_pda_account(
+            program_id,
+            payer,
+            profile_info,
+            system_program,
+            to_vec(&SologData::Profile(profile.clone()))?.len(),
+            &[PROFILE_SUFFIX, &owner.to_bytes(), &[profile_seed]],
+        )?;
+
+        return Ok(profile);
+    }
+
+    if profile_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    match SologData::try_from_slice(&profile_info.try_borrow_data()?)? {
+        SologData::Profile(profile) => Ok(profile),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
@@ -0,0 +1,41 @@
+use borsh::{to_vec, BorshDeserialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::set_return_data,
+    program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{
+    instruction::find_comment_address,
+    processor::{CommentSummary, SologData},
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [post_info, comment_info] = arrayref::array_ref![accounts, 0, 2];
+
+    if post_info.owner != program_id || comment_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let comment = match SologData::try_from_slice(&comment_info.try_borrow_data()?)? {
+        SologData::Comment(comment) => comment,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    let (expected_comment, _) =
+        find_comment_address(program_id, post_info.key, &comment.author, comment.order);
+    if comment_info.key != &expected_comment {
+        msg!("Comment does not belong to this post");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let summary = CommentSummary {
+        post: *post_info.key,
+        author: comment.author,
+        order: comment.order,
+        claps: comment.claps,
+        content: comment.content,
+    };
+
+    set_return_data(&to_vec(&summary)?);
+    Ok(())
+}
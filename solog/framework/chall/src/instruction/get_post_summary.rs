@@ -0,0 +1,45 @@
+use borsh::{to_vec, BorshDeserialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program::set_return_data,
+    program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{
+    instruction::find_post_address,
+    processor::{PostSummary, SologData},
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [post_info] = arrayref::array_ref![accounts, 0, 1];
+
+    if post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = match SologData::try_from_slice(&post_info.try_borrow_data()?)? {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    let (expected_post, _) = find_post_address(program_id, &post.author, &post.title);
+    if post_info.key != &expected_post {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let summary = PostSummary {
+        author: post.author,
+        claps: post.claps,
+        comment_count: post.comment_count,
+        collaborators: post.collaborators[..post.collaborator_count as usize].to_vec(),
+        pinned_comments: post.pinned_comments[..post.pinned_count as usize].to_vec(),
+        comments_locked: post.comments_locked,
+        price: post.price,
+        reposts: post.reposts,
+        repost_of: post.repost_of,
+        expires_at: post.expires_at,
+        title: post.title,
+    };
+
+    set_return_data(&to_vec(&summary)?);
+    Ok(())
+}
@@ -0,0 +1,107 @@
+use borsh::{to_vec, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    program_utils::limited_deserialize,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{
+        create_pda_account, find_config_address, find_program_data_address, CONFIG_SUFFIX,
+    },
+    processor::{Config, SologData},
+};
+
+pub fn instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    comment_slot_window: u64,
+    flag_threshold: u32,
+) -> ProgramResult {
+    let [admin, config_info, system_program, program_data_info] =
+        arrayref::array_ref![accounts, 0, 4];
+
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !config_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if system_program.key != &system_program::id() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    check_upgrade_authority(program_id, program_data_info, admin)?;
+
+    let (config, config_seed) = find_config_address(program_id);
+    if config_info.key != &config {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if !config_info.data_is_empty() {
+        msg!("Config already initialized");
+        return Err(ProgramError::AccountAlreadyInitialized);
+    }
+
+    let config_data = SologData::Config(Config {
+        admin: *admin.key,
+        comment_slot_window,
+        flag_threshold,
+    });
+
+    create_pda_account(
+        program_id,
+        admin,
+        config_info,
+        system_program,
+        to_vec(&config_data)?.len(),
+        &[CONFIG_SUFFIX, &[config_seed]],
+    )?;
+
+    config_data.serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::ConfigUpdated {
+        admin: *admin.key,
+        comment_slot_window,
+        flag_threshold,
+    }
+    .emit()?;
+    Ok(())
+}
+
+/// Only the upgrade authority of the program may become the admin, so that
+/// nobody can front-run the deployer to initialize the config
+fn check_upgrade_authority(
+    program_id: &Pubkey,
+    program_data_info: &AccountInfo,
+    admin: &AccountInfo,
+) -> ProgramResult {
+    let (program_data, _) = find_program_data_address(program_id);
+    if program_data_info.key != &program_data
+        || program_data_info.owner != &bpf_loader_upgradeable::id()
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let state = limited_deserialize(
+        &program_data_info.try_borrow_data()?,
+        UpgradeableLoaderState::size_of_programdata_metadata() as u64,
+    )
+    .map_err(|_| ProgramError::InvalidAccountData)?;
+
+    match state {
+        UpgradeableLoaderState::ProgramData {
+            upgrade_authority_address: Some(authority),
+            ..
+        } if authority == *admin.key => Ok(()),
+        _ => {
+            msg!("Only the upgrade authority can initialize the config");
+            Err(ProgramError::InvalidAccountData)
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{event::SologEvent, processor::SologData};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo], locked: bool) -> ProgramResult {
+    let [author, post_info] = arrayref::array_ref![accounts, 0, 2];
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !post_info.is_writable || post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let mut post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if post.author != *author.key {
+        msg!("Author mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    post.comments_locked = locked;
+
+    SologData::Post(post).serialize(&mut &mut post_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::CommentsLocked {
+        post: *post_info.key,
+        locked,
+    }
+    .emit()?;
+    Ok(())
+}
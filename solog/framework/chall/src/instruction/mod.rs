@@ -1,14 +1,292 @@
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::{invoke, invoke_signed},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction, system_program,
+    sysvar::Sysvar,
+};
+
+use crate::processor::{Bounty, Config, Post, PostHeader, Proposal, SologData};
+
 mod add_collaborator;
 mod add_comment;
+mod approve_proposal;
+mod award_bounty;
+mod block_commenter;
+mod cancel_proposal;
+mod close_expired;
+mod create_bounty;
 mod create_post;
 mod edit_comment;
+mod execute_proposal;
+mod flag;
+mod follow;
+mod get_comment;
+mod get_post_summary;
+mod init_config;
 mod clap;
+mod lock_comments;
+mod pin_comment;
+mod propose_edit;
+mod purchase_access;
+mod react;
+mod reclaim_bounty;
+mod repost;
+mod set_approval_threshold;
+mod set_moderator;
+mod set_post_expiry;
+mod set_post_price;
+mod unblock_commenter;
+mod unfollow;
+mod unhide;
+mod unpin_comment;
+mod update_config;
 
-const POST_SUFFIX: &[u8] = b"post";
-const COMMENT_SUFFIX: &[u8] = b"comment";
+pub use solog_types::pda::*;
 
 pub use self::add_collaborator::instruction as add_collaborator;
 pub use self::add_comment::instruction as add_comment;
+pub use self::approve_proposal::instruction as approve_proposal;
+pub use self::award_bounty::instruction as award_bounty;
+pub use self::block_commenter::instruction as block_commenter;
+pub use self::cancel_proposal::instruction as cancel_proposal;
+pub use self::close_expired::instruction as close_expired;
+pub use self::create_bounty::instruction as create_bounty;
 pub use self::create_post::instruction as create_post;
 pub use self::edit_comment::instruction as edit_comment;
-pub use self::clap::instruction as clap;
\ No newline at end of file
+pub use self::execute_proposal::instruction as execute_proposal;
+pub use self::flag::instruction as flag;
+pub use self::follow::instruction as follow;
+pub use self::get_comment::instruction as get_comment;
+pub use self::get_post_summary::instruction as get_post_summary;
+pub use self::init_config::instruction as init_config;
+pub use self::clap::instruction as clap;
+pub use self::lock_comments::instruction as lock_comments;
+pub use self::pin_comment::instruction as pin_comment;
+pub use self::propose_edit::instruction as propose_edit;
+pub use self::purchase_access::instruction as purchase_access;
+pub use self::react::instruction as react;
+pub use self::reclaim_bounty::instruction as reclaim_bounty;
+pub use self::repost::instruction as repost;
+pub use self::set_approval_threshold::instruction as set_approval_threshold;
+pub use self::set_moderator::instruction as set_moderator;
+pub use self::set_post_expiry::instruction as set_post_expiry;
+pub use self::set_post_price::instruction as set_post_price;
+pub use self::unblock_commenter::instruction as unblock_commenter;
+pub use self::unfollow::instruction as unfollow;
+pub use self::unhide::instruction as unhide;
+pub use self::unpin_comment::instruction as unpin_comment;
+pub use self::update_config::instruction as update_config;
+
+/// Load the program config, `None` if it has not been initialized yet
+fn load_config(
+    program_id: &Pubkey,
+    config_info: &AccountInfo,
+) -> Result<Option<Config>, ProgramError> {
+    let (config, _) = find_config_address(program_id);
+    if config_info.key != &config {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if config_info.data_is_empty() {
+        return Ok(None);
+    }
+
+    if config_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    match SologData::try_from_slice(&config_info.try_borrow_data()?)? {
+        SologData::Config(config) => Ok(Some(config)),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Load a proposal and check it was made on the post
+fn load_proposal(
+    program_id: &Pubkey,
+    post_info: &AccountInfo,
+    proposal_info: &AccountInfo,
+) -> Result<Proposal, ProgramError> {
+    if proposal_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let proposal = match SologData::try_from_slice(&proposal_info.try_borrow_data()?)? {
+        SologData::Proposal(proposal) => proposal,
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    let (expected_proposal, _) =
+        find_proposal_address(program_id, post_info.key, &proposal.proposer);
+    if proposal.post != *post_info.key || proposal_info.key != &expected_proposal {
+        msg!("Proposal does not belong to this post");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(proposal)
+}
+
+/// Check that `author` wrote the post and load its bounty vault
+fn load_bounty(
+    program_id: &Pubkey,
+    author: &AccountInfo,
+    post_info: &AccountInfo,
+    bounty_info: &AccountInfo,
+) -> Result<Bounty, ProgramError> {
+    if post_info.owner != program_id || bounty_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    match PostHeader::from_account_data(&post_info.try_borrow_data()?) {
+        Some(post) if post.author == *author.key => {}
+        Some(_) => {
+            msg!("Author mismatch");
+            return Err(ProgramError::InvalidAccountData);
+        }
+        None => return Err(ProgramError::InvalidInstructionData),
+    }
+
+    let (bounty, _) = find_bounty_address(program_id, post_info.key);
+    if bounty_info.key != &bounty {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    match SologData::try_from_slice(&bounty_info.try_borrow_data()?)? {
+        SologData::Bounty(bounty) => Ok(bounty),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
+
+/// Use the optional account at `index` to pay rent instead of the author,
+/// so that pdas of other programs holding no lamports can author through cpi
+fn payer_or<'a, 'b>(
+    accounts: &'b [AccountInfo<'a>],
+    index: usize,
+    author: &'b AccountInfo<'a>,
+) -> Result<&'b AccountInfo<'a>, ProgramError> {
+    let payer = accounts.get(index).unwrap_or(author);
+    if !payer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    Ok(payer)
+}
+
+/// Create a program owned pda funded by `payer`
+fn create_pda_account<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    pda_info: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    data_len: usize,
+    signer_seeds: &[&[u8]],
+) -> ProgramResult {
+    let rent = Rent::default().minimum_balance(data_len);
+
+    if pda_info.lamports() == 0 {
+        return invoke_signed(
+            &system_instruction::create_account(
+                payer.key,
+                pda_info.key,
+                rent,
+                data_len as u64,
+                program_id,
+            ),
+            &[payer.clone(), pda_info.clone(), system_program.clone()],
+            &[signer_seeds],
+        );
+    }
+
+    // anyone can send lamports to a pda before it is created, which makes
+    // `create_account` fail, so top it up and take it over instead
+    let top_up = rent.saturating_sub(pda_info.lamports());
+    if top_up > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, pda_info.key, top_up),
+            &[payer.clone(), pda_info.clone(), system_program.clone()],
+        )?;
+    }
+
+    invoke_signed(
+        &system_instruction::allocate(pda_info.key, data_len as u64),
+        &[pda_info.clone(), system_program.clone()],
+        &[signer_seeds],
+    )?;
+    invoke_signed(
+        &system_instruction::assign(pda_info.key, program_id),
+        &[pda_info.clone(), system_program.clone()],
+        &[signer_seeds],
+    )
+}
+
+/// Close a program owned account and refund its lamports to `destination`
+fn close_account(account_info: &AccountInfo, destination: &AccountInfo) -> ProgramResult {
+    let lamports = account_info.lamports();
+
+    **destination.try_borrow_mut_lamports()? = destination
+        .lamports()
+        .checked_add(lamports)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    **account_info.try_borrow_mut_lamports()? = 0;
+
+    account_info.realloc(0, false)?;
+    account_info.assign(&system_program::id());
+
+    Ok(())
+}
+
+/// Check that `commenter` is allowed to comment on the post
+fn check_commenter(
+    program_id: &Pubkey,
+    post_info: &AccountInfo,
+    post: &Post,
+    block_list_info: &AccountInfo,
+    commenter: &Pubkey,
+) -> ProgramResult {
+    if post.hidden {
+        msg!("Post is hidden");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if post.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("Post has expired");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if post.comments_locked && post.author != *commenter {
+        msg!("Comments are locked");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let (block_list, _) = find_block_list_address(program_id, post_info.key);
+    if block_list_info.key != &block_list {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // nobody has been blocked yet
+    if block_list_info.data_is_empty() {
+        return Ok(());
+    }
+
+    if block_list_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    match SologData::try_from_slice(&block_list_info.try_borrow_data()?)? {
+        SologData::BlockList(block_list) => {
+            if block_list.is_blocked(commenter) {
+                msg!("Commenter is blocked");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    }
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::find_comment_address,
+    processor::{SologData, MAX_PINNED_COMMENTS},
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [moderator, post_info, comment_info] = arrayref::array_ref![accounts, 0, 3];
+
+    if !moderator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !post_info.is_writable || post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if comment_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let mut post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if !post.is_moderator(moderator.key) {
+        msg!("Only the author or a moderator can pin comments");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let comment = SologData::try_from_slice(&comment_info.try_borrow_data()?)?;
+    let comment = match comment {
+        SologData::Comment(comment) => comment,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let (expected_comment, _) =
+        find_comment_address(program_id, post_info.key, &comment.author, comment.order);
+    if comment_info.key != &expected_comment {
+        msg!("Comment does not belong to this post");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if post.pinned_count >= MAX_PINNED_COMMENTS as u8 {
+        msg!("Too many pinned comments");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if post.pinned_comments[..post.pinned_count as usize].contains(comment_info.key) {
+        msg!("Comment already pinned");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    post.pinned_comments[post.pinned_count as usize] = *comment_info.key;
+    post.pinned_count += 1;
+
+    SologData::Post(post).serialize(&mut &mut post_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::CommentPinned {
+        post: *post_info.key,
+        comment: *comment_info.key,
+    }
+    .emit()?;
+    Ok(())
+}
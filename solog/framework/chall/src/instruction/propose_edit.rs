@@ -0,0 +1,101 @@
+use borsh::{to_vec, BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{create_pda_account, find_proposal_address, payer_or, PROPOSAL_SUFFIX},
+    processor::{Proposal, SologData},
+};
+
+pub fn instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    content: Vec<u8>,
+) -> ProgramResult {
+    let [proposer, post_info, proposal_info, system_program] = arrayref::array_ref![accounts, 0, 4];
+    let payer = payer_or(accounts, 4, proposer)?;
+
+    if !proposer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !proposal_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if system_program.key != &system_program::id() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if content.len() > 233 {
+        msg!("Post is too long");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let approver_index = match post.approver_index(proposer.key) {
+        Some(index) => index,
+        None => {
+            msg!("Only the author or a collaborator can propose edits");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    };
+
+    let (proposal, proposal_seed) =
+        find_proposal_address(program_id, post_info.key, proposer.key);
+    if proposal_info.key != &proposal {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if !proposal_info.data_is_empty() {
+        msg!("Proposal already pending");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // proposing counts as the first approval
+    let proposal_data = SologData::Proposal(Proposal {
+        post: *post_info.key,
+        proposer: *proposer.key,
+        approvals: 1 << approver_index,
+        content,
+    });
+
+    create_pda_account(
+        program_id,
+        payer,
+        proposal_info,
+        system_program,
+        to_vec(&proposal_data)?.len(),
+        &[
+            PROPOSAL_SUFFIX,
+            &post_info.key.to_bytes(),
+            &proposer.key.to_bytes(),
+            &[proposal_seed],
+        ],
+    )?;
+
+    proposal_data.serialize(&mut &mut proposal_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::ProposalCreated {
+        post: *post_info.key,
+        proposal: *proposal_info.key,
+        proposer: *proposer.key,
+    }
+    .emit()?;
+    Ok(())
+}
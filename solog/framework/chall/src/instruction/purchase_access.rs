@@ -0,0 +1,117 @@
+use borsh::{to_vec, BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_instruction, system_program,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{create_pda_account, find_access_receipt_address, ACCESS_SUFFIX},
+    processor::{AccessReceipt, SologData},
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [reader, post_info, receipt_info, system_program, author] =
+        arrayref::array_ref![accounts, 0, 5];
+    let collaborators = &accounts[5..];
+
+    if !reader.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !receipt_info.is_writable || !receipt_info.data_is_empty() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if system_program.key != &system_program::id() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if post.price == 0 {
+        msg!("Post is free");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if post.author != *author.key {
+        msg!("Author mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let collaborator_count = post.collaborator_count as usize;
+    if collaborators.len() < collaborator_count {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+    let expected_collaborators = &post.collaborators[..collaborator_count];
+    for (collaborator, expected) in collaborators.iter().zip(expected_collaborators) {
+        if collaborator.key != expected {
+            msg!("Collaborator mismatch");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    }
+
+    let (receipt, receipt_seed) =
+        find_access_receipt_address(program_id, post_info.key, reader.key);
+    if receipt_info.key != &receipt {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // collaborators get an equal share, the author also keeps the remainder
+    let share = post.price / (collaborator_count as u64 + 1);
+    let author_share = post.price - share * collaborator_count as u64;
+
+    invoke(
+        &system_instruction::transfer(reader.key, author.key, author_share),
+        &[reader.clone(), author.clone(), system_program.clone()],
+    )?;
+    for collaborator in &collaborators[..collaborator_count] {
+        invoke(
+            &system_instruction::transfer(reader.key, collaborator.key, share),
+            &[reader.clone(), collaborator.clone(), system_program.clone()],
+        )?;
+    }
+
+    let receipt_data = SologData::AccessReceipt(AccessReceipt {
+        post: *post_info.key,
+        reader: *reader.key,
+        price: post.price,
+    });
+
+    create_pda_account(
+        program_id,
+        reader,
+        receipt_info,
+        system_program,
+        to_vec(&receipt_data)?.len(),
+        &[
+            ACCESS_SUFFIX,
+            &post_info.key.to_bytes(),
+            &reader.key.to_bytes(),
+            &[receipt_seed],
+        ],
+    )?;
+
+    receipt_data.serialize(&mut &mut receipt_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::AccessPurchased {
+        post: *post_info.key,
+        reader: *reader.key,
+        price: post.price,
+    }
+    .emit()?;
+    Ok(())
+}
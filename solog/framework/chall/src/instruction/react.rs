@@ -0,0 +1,151 @@
+use borsh::{to_vec, BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{
+        create_pda_account, find_reaction_address, find_reactions_address, payer_or,
+        REACTIONS_SUFFIX, REACTION_SUFFIX,
+    },
+    processor::{
+        CommentHeader, PostHeader, Reaction, ReactionKind, Reactions, SologData, REACTION_KINDS,
+    },
+};
+
+pub fn instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    kind: ReactionKind,
+) -> ProgramResult {
+    let [viewer, target_info, reactions_info, reaction_info, system_program] =
+        arrayref::array_ref![accounts, 0, 5];
+    let payer = payer_or(accounts, 5, viewer)?;
+
+    if !viewer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !reactions_info.is_writable || !reaction_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if system_program.key != &system_program::id() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if target_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    {
+        let data = target_info.try_borrow_data()?;
+        if PostHeader::from_account_data(&data).is_none()
+            && CommentHeader::from_account_data(&data).is_none()
+        {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    }
+
+    let mut reactions = load_or_create(
+        program_id,
+        payer,
+        reactions_info,
+        system_program,
+        find_reactions_address(program_id, target_info.key),
+        &[REACTIONS_SUFFIX, &target_info.key.to_bytes()],
+        || {
+            SologData::Reactions(Reactions {
+                target: *target_info.key,
+                counts: [0; REACTION_KINDS],
+            })
+        },
+    )?;
+    let mut reaction = load_or_create(
+        program_id,
+        payer,
+        reaction_info,
+        system_program,
+        find_reaction_address(program_id, target_info.key, viewer.key),
+        &[REACTION_SUFFIX, &target_info.key.to_bytes(), &viewer.key.to_bytes()],
+        || {
+            SologData::Reaction(Reaction {
+                target: *target_info.key,
+                viewer: *viewer.key,
+                kinds: 0,
+            })
+        },
+    )?;
+
+    let totals = match (&mut reactions, &mut reaction) {
+        (SologData::Reactions(reactions), SologData::Reaction(reaction)) => {
+            let bit = 1 << kind.index();
+            if reaction.kinds & bit != 0 {
+                msg!("Already reacted");
+                return Err(ProgramError::InvalidInstructionData);
+            }
+            reaction.kinds |= bit;
+
+            let count = &mut reactions.counts[kind.index()];
+            *count = count.checked_add(1).ok_or(ProgramError::ArithmeticOverflow)?;
+            reactions.counts
+        }
+        _ => return Err(ProgramError::InvalidAccountData),
+    };
+
+    reactions.serialize(&mut &mut reactions_info.try_borrow_mut_data()?[..])?;
+    reaction.serialize(&mut &mut reaction_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::Reacted {
+        target: *target_info.key,
+        viewer: *viewer.key,
+        kind,
+        totals,
+    }
+    .emit()?;
+    Ok(())
+}
+
+/// Load the account at `address`, creating it with `init` at the expense of
+/// `payer` on first use
+fn load_or_create<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    account_info: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    (address, seed): (Pubkey, u8),
+    seeds: &[&[u8]],
+    init: impl FnOnce() -> SologData,
+) -> Result<SologData, ProgramError> {
+    if account_info.key != &address {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if account_info.data_is_empty() {
+        let data = init();
+        let seed = [seed];
+
+        create_pda_account(
+            program_id,
+            payer,
+            account_info,
+            system_program,
+            to_vec(&data)?.len(),
+            &[seeds, &[&seed[..]]].concat(),
+        )?;
+
+        return Ok(data);
+    }
+
+    if account_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(SologData::try_from_slice(&account_info.try_borrow_data()?)?)
+}
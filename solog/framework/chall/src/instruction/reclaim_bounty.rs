@@ -0,0 +1,42 @@
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    sysvar::Sysvar,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{close_account, load_bounty},
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [author, post_info, bounty_info] = arrayref::array_ref![accounts, 0, 3];
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !bounty_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let bounty = load_bounty(program_id, author, post_info, bounty_info)?;
+
+    if Clock::get()?.unix_timestamp < bounty.expires_at {
+        msg!("Bounty has not expired yet");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    close_account(bounty_info, author)?;
+
+    SologEvent::BountyReclaimed {
+        post: *post_info.key,
+        amount: bounty.amount,
+    }
+    .emit()?;
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use borsh::{to_vec, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{create_pda_account, find_post_address, payer_or, POST_SUFFIX},
+    processor::{Post, PostHeader, SologData},
+};
+
+pub fn instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    title: Vec<u8>,
+    content: Vec<u8>,
+) -> ProgramResult {
+    let [author, post_info, original_info, system_program] = arrayref::array_ref![accounts, 0, 4];
+    let payer = payer_or(accounts, 4, author)?;
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !post_info.is_writable || !original_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if system_program.key != &system_program::id() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if title.len() > 20 || content.len() > 233 {
+        msg!("Post is too long");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if original_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let reposts = {
+        let mut data = original_info.try_borrow_mut_data()?;
+        let original = match PostHeader::from_account_data_mut(&mut data) {
+            Some(original) => original,
+            None => {
+                msg!("Can only repost posts");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        };
+
+        let reposts = original
+            .reposts()
+            .checked_add(1)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        original.set_reposts(reposts);
+        reposts
+    };
+
+    let (post, post_seed) = find_post_address(program_id, author.key, &title);
+    if post_info.key != &post {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    if !post_info.data_is_empty() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post_data = SologData::Post(Post {
+        repost_of: Some(*original_info.key),
+        rent_payer: *payer.key,
+        ..Post::new(*author.key, title.clone(), content)
+    });
+
+    create_pda_account(
+        program_id,
+        payer,
+        post_info,
+        system_program,
+        to_vec(&post_data)?.len(),
+        &[POST_SUFFIX, &author.key.to_bytes(), &title, &[post_seed]],
+    )?;
+
+    post_data.serialize(&mut &mut post_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::Reposted {
+        post: *post_info.key,
+        original: *original_info.key,
+        author: *author.key,
+        reposts,
+    }
+    .emit()?;
+    Ok(())
+}
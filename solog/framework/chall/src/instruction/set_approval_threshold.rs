@@ -0,0 +1,53 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{event::SologEvent, processor::SologData};
+
+pub fn instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    threshold: u8,
+    require_author: bool,
+) -> ProgramResult {
+    let [author, post_info] = arrayref::array_ref![accounts, 0, 2];
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !post_info.is_writable || post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let mut post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if post.author != *author.key {
+        msg!("Author mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // the author and every collaborator can approve once
+    if threshold == 0 || threshold > post.collaborator_count + 1 {
+        msg!("Invalid approval threshold");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    post.approval_threshold = threshold;
+    post.require_author = require_author;
+
+    SologData::Post(post).serialize(&mut &mut post_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::ApprovalThresholdSet {
+        post: *post_info.key,
+        threshold,
+        require_author,
+    }
+    .emit()?;
+    Ok(())
+}
@@ -0,0 +1,58 @@
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{event::SologEvent, processor::PostHeader};
+
+pub fn instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    moderator: bool,
+) -> ProgramResult {
+    let [author, collaborator, post_info] = arrayref::array_ref![accounts, 0, 3];
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !post_info.is_writable || post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut data = post_info.try_borrow_mut_data()?;
+    let post = match PostHeader::from_account_data_mut(&mut data) {
+        Some(post) => post,
+        None => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if post.author != *author.key {
+        msg!("Author mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let index = match post.collaborators[..post.collaborator_count as usize]
+        .iter()
+        .position(|key| key == collaborator.key)
+    {
+        Some(index) => index,
+        None => {
+            msg!("Not a collaborator of the post");
+            return Err(ProgramError::InvalidAccountData);
+        }
+    };
+
+    if moderator {
+        post.moderators |= 1 << index;
+    } else {
+        post.moderators &= !(1 << index);
+    }
+
+    SologEvent::ModeratorSet {
+        post: *post_info.key,
+        collaborator: *collaborator.key,
+        moderator,
+    }
+    .emit()?;
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use borsh::{to_vec, BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    clock::Clock,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction,
+    sysvar::Sysvar,
+};
+
+use crate::{event::SologEvent, instruction::payer_or, processor::SologData};
+
+pub fn instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    expires_at: Option<i64>,
+) -> ProgramResult {
+    let [author, post_info, system_program] = arrayref::array_ref![accounts, 0, 3];
+    let payer = payer_or(accounts, 3, author)?;
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !post_info.is_writable || post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let mut post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if post.author != *author.key {
+        msg!("Author mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    // an expired post may already be partly closed, it can't be revived
+    let now = Clock::get()?.unix_timestamp;
+    if post.is_expired(now) {
+        msg!("Post has expired");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    if expires_at.is_some_and(|expires_at| expires_at <= now) {
+        msg!("Expiry must be in the future");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    post.expires_at = expires_at;
+    let post = SologData::Post(post);
+
+    // setting the first expiry grows the post by the timestamp
+    let new_post_data_len = to_vec(&post)?.len();
+    let new_rent = Rent::default().minimum_balance(new_post_data_len);
+    let diff_rent = new_rent.saturating_sub(post_info.lamports());
+
+    invoke(
+        &system_instruction::transfer(payer.key, post_info.key, diff_rent),
+        &[payer.clone(), post_info.clone(), system_program.clone()],
+    )?;
+
+    post_info.realloc(new_post_data_len, false)?;
+    post.serialize(&mut &mut post_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::PostExpirySet {
+        post: *post_info.key,
+        expires_at,
+    }
+    .emit()?;
+    Ok(())
+}
@@ -0,0 +1,88 @@
+use borsh::{to_vec, BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    msg,
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    rent::Rent,
+    system_instruction,
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::payer_or,
+    processor::{SologData, MAX_GATED_CONTENT_LEN},
+};
+
+pub fn instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    price: u64,
+    gated_content: Vec<u8>,
+) -> ProgramResult {
+    let [author, post_info, system_program] = arrayref::array_ref![accounts, 0, 3];
+    let payer = payer_or(accounts, 3, author)?;
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !post_info.is_writable || post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if gated_content.len() > MAX_GATED_CONTENT_LEN {
+        msg!("Gated content reference is too long");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let mut post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if post.author != *author.key {
+        msg!("Author mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    post.price = price;
+    post.gated_content = gated_content;
+    let post = SologData::Post(post);
+
+    let new_post_data_len = to_vec(&post)?.len();
+    let new_rent = Rent::default().minimum_balance(new_post_data_len);
+    let diff_rent = new_rent.saturating_sub(post_info.lamports());
+
+    invoke(
+        &system_instruction::transfer(payer.key, post_info.key, diff_rent),
+        &[payer.clone(), post_info.clone(), system_program.clone()],
+    )?;
+
+    post_info.realloc(new_post_data_len, false)?;
+    post.serialize(&mut &mut post_info.try_borrow_mut_data()?[..])?;
+
+    // a shorter gated content reference frees rent, refund it to the author
+    let excess_rent = post_info.lamports().saturating_sub(new_rent);
+    if excess_rent > 0 {
+        if !author.is_writable {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        **post_info.try_borrow_mut_lamports()? -= excess_rent;
+        **author.try_borrow_mut_lamports()? = author
+            .lamports()
+            .checked_add(excess_rent)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+    }
+
+    SologEvent::PostPriceSet {
+        post: *post_info.key,
+        price,
+    }
+    .emit()?;
+    Ok(())
+}
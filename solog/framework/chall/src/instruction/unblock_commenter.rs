@@ -0,0 +1,70 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{event::SologEvent, instruction::find_block_list_address, processor::SologData};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [author, post_info, commenter, block_list_info] = arrayref::array_ref![accounts, 0, 4];
+
+    if !author.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if !block_list_info.is_writable || block_list_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if post.author != *author.key {
+        msg!("Author mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (block_list, _) = find_block_list_address(program_id, post_info.key);
+    if block_list_info.key != &block_list {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let block_list = SologData::try_from_slice(&block_list_info.try_borrow_data()?)?;
+    let mut block_list = match block_list {
+        SologData::BlockList(block_list) => block_list,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    let blocked_count = block_list.blocked_count as usize;
+    let index = match block_list.blocked[..blocked_count]
+        .iter()
+        .position(|blocked| blocked == commenter.key)
+    {
+        Some(index) => index,
+        None => {
+            msg!("Commenter is not blocked");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    };
+
+    block_list.blocked.copy_within(index + 1..blocked_count, index);
+    block_list.blocked[blocked_count - 1] = Pubkey::default();
+    block_list.blocked_count -= 1;
+
+    SologData::BlockList(block_list)
+        .serialize(&mut &mut block_list_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::CommenterUnblocked {
+        post: *post_info.key,
+        commenter: *commenter.key,
+    }
+    .emit()?;
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{close_account, find_follow_address, find_profile_address},
+    processor::{Profile, SologData},
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [follower, following, follower_profile_info, following_profile_info, follow_info] =
+        arrayref::array_ref![accounts, 0, 5];
+
+    if !follower.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !follower_profile_info.is_writable
+        || !following_profile_info.is_writable
+        || !follow_info.is_writable
+    {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let (follow, _) = find_follow_address(program_id, follower.key, following.key);
+    if follow_info.key != &follow || follow_info.owner != program_id {
+        msg!("Not following");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut follower_profile = load_profile(program_id, follower.key, follower_profile_info)?;
+    let mut following_profile = load_profile(program_id, following.key, following_profile_info)?;
+
+    follower_profile.following_count = follower_profile.following_count.saturating_sub(1);
+    following_profile.follower_count = following_profile.follower_count.saturating_sub(1);
+
+    SologData::Profile(follower_profile)
+        .serialize(&mut &mut follower_profile_info.try_borrow_mut_data()?[..])?;
+    SologData::Profile(following_profile)
+        .serialize(&mut &mut following_profile_info.try_borrow_mut_data()?[..])?;
+
+    close_account(follow_info, follower)?;
+
+    SologEvent::Unfollowed {
+        follower: *follower.key,
+        following: *following.key,
+    }
+    .emit()?;
+    Ok(())
+}
+
+fn load_profile(
+    program_id: &Pubkey,
+    owner: &Pubkey,
+    profile_info: &AccountInfo,
+) -> Result<Profile, ProgramError> {
+    let (profile, _) = find_profile_address(program_id, owner);
+    if profile_info.key != &profile || profile_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    match SologData::try_from_slice(&profile_info.try_borrow_data()?)? {
+        SologData::Profile(profile) => Ok(profile),
+        _ => Err(ProgramError::InvalidAccountData),
+    }
+}
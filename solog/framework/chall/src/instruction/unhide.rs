@@ -0,0 +1,109 @@
+use borsh::BorshDeserialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{
+    event::SologEvent,
+    instruction::{close_account, find_comment_address, find_flag_address, load_config},
+    processor::{CommentHeader, PostHeader, SologData},
+};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [reviewer, target_info, config_info, post_info] = arrayref::array_ref![accounts, 0, 4];
+    let flags = accounts[4..].chunks_exact(2);
+
+    if !flags.remainder().is_empty() {
+        msg!("Every flag must come with its flagger");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    if !reviewer.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !target_info.is_writable || target_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let is_admin = load_config(program_id, config_info)?
+        .is_some_and(|config| config.admin == *reviewer.key);
+
+    {
+        let data = target_info.try_borrow_data()?;
+
+        if let Some(post) = PostHeader::from_account_data(&data) {
+            if post_info.key != target_info.key {
+                msg!("Post mismatch");
+                return Err(ProgramError::InvalidAccountData);
+            }
+            if post.author != *reviewer.key && !is_admin {
+                msg!("Only the author or the admin can unhide a post");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        } else if let Some(comment) = CommentHeader::from_account_data(&data) {
+            let (expected_comment, _) =
+                find_comment_address(program_id, post_info.key, &comment.author, comment.order);
+            if target_info.key != &expected_comment || post_info.owner != program_id {
+                msg!("Comment does not belong to this post");
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            let post = match SologData::try_from_slice(&post_info.try_borrow_data()?)? {
+                SologData::Post(post) => post,
+                _ => return Err(ProgramError::InvalidAccountData),
+            };
+            if !post.is_moderator(reviewer.key) && !is_admin {
+                msg!("Only a moderator of the post or the admin can unhide a comment");
+                return Err(ProgramError::InvalidAccountData);
+            }
+        } else {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    }
+
+    // closing the flags lets their flaggers flag the target again
+    let mut cleared: u32 = 0;
+    for pair in flags {
+        let [flag_info, flagger] = arrayref::array_ref![pair, 0, 2];
+
+        let (flag, _) = find_flag_address(program_id, target_info.key, flagger.key);
+        if flag_info.key != &flag || flag_info.owner != program_id {
+            msg!("Not a flag of this target");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if !flag_info.is_writable || !flagger.is_writable {
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        close_account(flag_info, flagger)?;
+        cleared += 1;
+    }
+
+    let flags = {
+        let mut data = target_info.try_borrow_mut_data()?;
+
+        if let Some(post) = PostHeader::from_account_data_mut(&mut data) {
+            let flags = post.flags().saturating_sub(cleared);
+            post.set_flags(flags);
+            post.set_hidden(false);
+            flags
+        } else if let Some(comment) = CommentHeader::from_account_data_mut(&mut data) {
+            let flags = comment.flags().saturating_sub(cleared);
+            comment.set_flags(flags);
+            comment.set_hidden(false);
+            flags
+        } else {
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    };
+
+    SologEvent::Unhidden {
+        target: *target_info.key,
+        reviewer: *reviewer.key,
+        flags,
+    }
+    .emit()?;
+    Ok(())
+}
@@ -0,0 +1,55 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{event::SologEvent, processor::SologData};
+
+pub fn instruction(program_id: &Pubkey, accounts: &[AccountInfo]) -> ProgramResult {
+    let [moderator, post_info, comment_info] = arrayref::array_ref![accounts, 0, 3];
+
+    if !moderator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if !post_info.is_writable || post_info.owner != program_id {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let post = SologData::try_from_slice(&post_info.try_borrow_data()?)?;
+    let mut post = match post {
+        SologData::Post(post) => post,
+        _ => return Err(ProgramError::InvalidInstructionData),
+    };
+
+    if !post.is_moderator(moderator.key) {
+        msg!("Only the author or a moderator can unpin comments");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let pinned_count = post.pinned_count as usize;
+    let index = match post.pinned_comments[..pinned_count]
+        .iter()
+        .position(|pinned| pinned == comment_info.key)
+    {
+        Some(index) => index,
+        None => {
+            msg!("Comment is not pinned");
+            return Err(ProgramError::InvalidInstructionData);
+        }
+    };
+
+    // keep the pinned comments packed at the front
+    post.pinned_comments.copy_within(index + 1..pinned_count, index);
+    post.pinned_comments[pinned_count - 1] = Pubkey::default();
+    post.pinned_count -= 1;
+
+    SologData::Post(post).serialize(&mut &mut post_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::CommentUnpinned {
+        post: *post_info.key,
+        comment: *comment_info.key,
+    }
+    .emit()?;
+    Ok(())
+}
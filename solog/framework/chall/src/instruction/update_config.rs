@@ -0,0 +1,45 @@
+use borsh::BorshSerialize;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError, pubkey::Pubkey
+};
+
+use crate::{event::SologEvent, instruction::load_config, processor::SologData};
+
+pub fn instruction(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo],
+    comment_slot_window: u64,
+    flag_threshold: u32,
+) -> ProgramResult {
+    let [admin, config_info] = arrayref::array_ref![accounts, 0, 2];
+
+    if !admin.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+    if !config_info.is_writable {
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    let mut config = match load_config(program_id, config_info)? {
+        Some(config) => config,
+        None => return Err(ProgramError::UninitializedAccount),
+    };
+
+    if config.admin != *admin.key {
+        msg!("Admin mismatch");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    config.comment_slot_window = comment_slot_window;
+    config.flag_threshold = flag_threshold;
+
+    SologData::Config(config).serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+
+    SologEvent::ConfigUpdated {
+        admin: *admin.key,
+        comment_slot_window,
+        flag_threshold,
+    }
+    .emit()?;
+    Ok(())
+}
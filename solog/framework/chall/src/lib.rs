@@ -1,9 +1,11 @@
-use solana_program::pubkey;
-use solana_program::pubkey::Pubkey;
+pub use solog_types::{builder, event, ID};
 
 mod entrypoint;
+pub mod cpi;
+#[cfg(feature = "dev-tools")]
 pub mod dev;
+pub mod heap;
 pub mod instruction;
 pub mod processor;
 
-pub const ID: Pubkey = pubkey!("so1og11111111111111111111111111111111111111");
+pub use entrypoint::process_instruction;
@@ -0,0 +1,179 @@
+mod common;
+
+use chall::{builder, instruction::find_access_receipt_address};
+use common::{instruction_error, Env};
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+};
+
+const PRICE: u64 = 1_000_000;
+
+struct Paywall {
+    author: Keypair,
+    collaborators: [Pubkey; 2],
+    post: Pubkey,
+}
+
+/// A post priced at `PRICE` with two collaborators
+async fn paywall(env: &mut Env) -> Paywall {
+    let author = env.funded_keypair().await;
+    let post = env.create_post(&author, "paid").await;
+
+    // funded so that they can receive shares below the rent exemption
+    let mut collaborators = [Pubkey::default(); 2];
+    for collaborator in &mut collaborators {
+        *collaborator = env.funded_keypair().await.pubkey();
+        env.add_collaborator(&author, collaborator, &post).await;
+    }
+
+    env.process(
+        &[builder::set_post_price(
+            &chall::ID,
+            &author.pubkey(),
+            &post,
+            PRICE,
+            b"ipfs://cid".to_vec(),
+        )],
+        &[&author],
+    )
+    .await
+    .unwrap();
+
+    Paywall {
+        author,
+        collaborators,
+        post,
+    }
+}
+
+fn purchase(reader: &Keypair, paywall: &Paywall, collaborators: &[Pubkey]) -> Instruction {
+    builder::purchase_access(
+        &chall::ID,
+        &reader.pubkey(),
+        &paywall.post,
+        &paywall.author.pubkey(),
+        collaborators,
+    )
+}
+
+#[tokio::test]
+async fn price_is_split_with_the_remainder_to_the_author() {
+    let mut env = Env::start().await;
+    let paywall = paywall(&mut env).await;
+    let reader = env.funded_keypair().await;
+
+    let mut accounts = vec![paywall.author.pubkey()];
+    accounts.extend(paywall.collaborators);
+    let mut balances = Vec::new();
+    for account in &accounts {
+        balances.push(env.lamports(account).await);
+    }
+
+    env.process(
+        &[purchase(&reader, &paywall, &paywall.collaborators)],
+        &[&reader],
+    )
+    .await
+    .unwrap();
+
+    // 1_000_000 split in three leaves one lamport for the author
+    let shares = [333_334, 333_333, 333_333];
+    for ((account, balance), share) in accounts.iter().zip(balances).zip(shares) {
+        assert_eq!(env.lamports(account).await, balance + share);
+    }
+
+    let (receipt, _) = find_access_receipt_address(&chall::ID, &paywall.post, &reader.pubkey());
+    assert!(env.account(&receipt).await.is_some());
+}
+
+#[tokio::test]
+async fn access_is_purchased_once() {
+    let mut env = Env::start().await;
+    let paywall = paywall(&mut env).await;
+    let reader = env.funded_keypair().await;
+
+    env.process(
+        &[purchase(&reader, &paywall, &paywall.collaborators)],
+        &[&reader],
+    )
+    .await
+    .unwrap();
+
+    let balance = env.lamports(&reader.pubkey()).await;
+    assert_eq!(
+        instruction_error(
+            env.process(
+                &[purchase(&reader, &paywall, &paywall.collaborators)],
+                &[&reader]
+            )
+            .await
+        ),
+        InstructionError::InvalidAccountData
+    );
+    assert_eq!(env.lamports(&reader.pubkey()).await, balance);
+}
+
+#[tokio::test]
+async fn collaborators_are_passed_in_post_order() {
+    let mut env = Env::start().await;
+    let paywall = paywall(&mut env).await;
+    let reader = env.funded_keypair().await;
+    let [first, second] = paywall.collaborators;
+
+    let cases = [
+        (vec![second, first], InstructionError::InvalidAccountData),
+        (
+            vec![first, Pubkey::new_unique()],
+            InstructionError::InvalidAccountData,
+        ),
+        (vec![first], InstructionError::NotEnoughAccountKeys),
+    ];
+    for (collaborators, error) in cases {
+        assert_eq!(
+            instruction_error(
+                env.process(&[purchase(&reader, &paywall, &collaborators)], &[&reader])
+                    .await
+            ),
+            error
+        );
+    }
+}
+
+#[tokio::test]
+async fn shorter_gated_content_refunds_rent_to_the_author() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let post = env.create_post(&author, "paid").await;
+
+    let set_price = |gated_content: &[u8]| {
+        builder::set_post_price(
+            &chall::ID,
+            &author.pubkey(),
+            &post,
+            PRICE,
+            gated_content.to_vec(),
+        )
+    };
+    env.process(&[set_price(&[b'x'; 128])], &[&author])
+        .await
+        .unwrap();
+    let rent = env.lamports(&post).await;
+    let balance = env.lamports(&author.pubkey()).await;
+
+    env.process(&[set_price(b"short")], &[&author])
+        .await
+        .unwrap();
+
+    let account = env.account(&post).await.unwrap();
+    assert_eq!(
+        account.lamports,
+        Rent::default().minimum_balance(account.data.len())
+    );
+    assert_eq!(
+        env.lamports(&author.pubkey()).await,
+        balance + rent - account.lamports
+    );
+}
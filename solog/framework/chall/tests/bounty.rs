@@ -0,0 +1,93 @@
+mod common;
+
+use chall::{
+    builder,
+    instruction::{find_bounty_address, find_comment_address},
+};
+use common::{instruction_error, Env};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const BOUNTY: u64 = 100_000_000;
+
+async fn create_bounty(env: &mut Env, author: &Keypair, post: &Pubkey) {
+    let expires_at = env.now().await + 3600;
+    env.process(
+        &[builder::create_bounty(&chall::ID, &author.pubkey(), post, BOUNTY, expires_at)],
+        &[author],
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn bounty_is_awarded_once() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let first = env.funded_keypair().await;
+    let second = env.funded_keypair().await;
+    let post = env.create_post(&author, "bountied").await;
+    env.add_comment(&first, &post, 0).await.unwrap();
+    env.add_comment(&second, &post, 1).await.unwrap();
+    create_bounty(&mut env, &author, &post).await;
+
+    let award = |winner: &Pubkey, order| {
+        let (comment, _) = find_comment_address(&chall::ID, &post, winner, order);
+        builder::award_bounty(&chall::ID, &author.pubkey(), &post, &comment, winner)
+    };
+    let award_first = award(&first.pubkey(), 0);
+    let award_second = award(&second.pubkey(), 1);
+
+    // not even within one transaction
+    assert_eq!(
+        instruction_error(
+            env.process(&[award_first.clone(), award_second.clone()], &[&author])
+                .await
+        ),
+        InstructionError::InvalidAccountData
+    );
+
+    let balance = env.lamports(&first.pubkey()).await;
+    env.process(&[award_first], &[&author]).await.unwrap();
+    assert_eq!(env.lamports(&first.pubkey()).await, balance + BOUNTY);
+
+    let balance = env.lamports(&second.pubkey()).await;
+    assert_eq!(
+        instruction_error(env.process(&[award_second], &[&author]).await),
+        InstructionError::InvalidAccountData
+    );
+    assert_eq!(env.lamports(&second.pubkey()).await, balance);
+}
+
+#[tokio::test]
+async fn bounty_ignores_comments_of_other_posts() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let commenter = env.funded_keypair().await;
+    let post = env.create_post(&author, "bountied").await;
+    let other = env.create_post(&author, "other").await;
+    env.add_comment(&commenter, &other, 0).await.unwrap();
+    create_bounty(&mut env, &author, &post).await;
+
+    let (comment, _) = find_comment_address(&chall::ID, &other, &commenter.pubkey(), 0);
+    let (bounty, _) = find_bounty_address(&chall::ID, &post);
+    let vault = env.lamports(&bounty).await;
+
+    let result = env
+        .process(
+            &[builder::award_bounty(
+                &chall::ID,
+                &author.pubkey(),
+                &post,
+                &comment,
+                &commenter.pubkey(),
+            )],
+            &[&author],
+        )
+        .await;
+    assert_eq!(instruction_error(result), InstructionError::InvalidAccountData);
+    assert_eq!(env.lamports(&bounty).await, vault);
+}
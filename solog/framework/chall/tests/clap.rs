@@ -0,0 +1,41 @@
+mod common;
+
+use chall::builder;
+use common::Env;
+use solana_program_test::BanksClientError;
+use solana_sdk::signature::Signer;
+
+async fn clap_post(data: Vec<u8>) -> Result<(), BanksClientError> {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let viewer = env.funded_keypair().await;
+    let post = env.create_post(&author, "clapped").await;
+
+    env.process(
+        &[builder::clap(&chall::ID, &viewer.pubkey(), &post, data)],
+        &[&viewer],
+    )
+    .await
+}
+
+#[cfg(not(feature = "dev-tools"))]
+#[tokio::test]
+async fn clap_data_needs_dev_tools() {
+    use common::instruction_error;
+    use solana_sdk::instruction::InstructionError;
+
+    clap_post(Vec::new()).await.unwrap();
+    assert_eq!(
+        instruction_error(clap_post(vec![0]).await),
+        InstructionError::InvalidInstructionData
+    );
+}
+
+#[cfg(feature = "dev-tools")]
+#[tokio::test]
+async fn clap_data_runs_heap_kit() {
+    use chall::processor::HeapKit;
+
+    let data = borsh::to_vec(&HeapKit { commands: Vec::new() }).unwrap();
+    clap_post(data).await.unwrap();
+}
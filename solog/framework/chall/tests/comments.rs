@@ -0,0 +1,133 @@
+mod common;
+
+use chall::{
+    builder,
+    instruction::{find_block_list_address, find_comment_address},
+    processor::{SologData, MAX_BLOCKED_COMMENTERS},
+};
+use common::{instruction_error, Env};
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+fn edit_comment(author: &Keypair, post: &Pubkey, order: u8) -> Instruction {
+    builder::edit_comment(&chall::ID, &author.pubkey(), post, order, b"edited".to_vec())
+}
+
+fn block(author: &Keypair, post: &Pubkey, commenter: &Pubkey) -> Instruction {
+    builder::block_commenter(&chall::ID, &author.pubkey(), post, commenter)
+}
+
+#[tokio::test]
+async fn locked_posts_take_no_comments_or_edits() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let commenter = env.funded_keypair().await;
+    let post = env.create_post(&author, "locked").await;
+    env.add_comment(&commenter, &post, 0).await.unwrap();
+
+    let lock = |locked| builder::lock_comments(&chall::ID, &author.pubkey(), &post, locked);
+    env.process(&[lock(true)], &[&author]).await.unwrap();
+
+    assert_eq!(
+        instruction_error(env.add_comment(&commenter, &post, 1).await),
+        InstructionError::InvalidInstructionData
+    );
+    assert_eq!(
+        instruction_error(
+            env.process(&[edit_comment(&commenter, &post, 0)], &[&commenter])
+                .await
+        ),
+        InstructionError::InvalidInstructionData
+    );
+    // the author still comments on their own locked post
+    env.add_comment(&author, &post, 1).await.unwrap();
+
+    env.process(&[lock(false)], &[&author]).await.unwrap();
+    env.add_comment(&commenter, &post, 2).await.unwrap();
+    env.process(&[edit_comment(&commenter, &post, 0)], &[&commenter])
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn blocked_commenters_neither_comment_nor_edit_until_unblocked() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let commenter = env.funded_keypair().await;
+    let post = env.create_post(&author, "blocking").await;
+    env.add_comment(&commenter, &post, 0).await.unwrap();
+
+    env.process(&[block(&author, &post, &commenter.pubkey())], &[&author])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        instruction_error(env.add_comment(&commenter, &post, 1).await),
+        InstructionError::InvalidAccountData
+    );
+    assert_eq!(
+        instruction_error(
+            env.process(&[edit_comment(&commenter, &post, 0)], &[&commenter])
+                .await
+        ),
+        InstructionError::InvalidAccountData
+    );
+
+    // leaving out the post and block list does not skip the check
+    let mut edit = edit_comment(&commenter, &post, 0);
+    edit.accounts.truncate(3);
+    assert_eq!(
+        instruction_error(env.process(&[edit], &[&commenter]).await),
+        InstructionError::NotEnoughAccountKeys
+    );
+
+    let unblock =
+        builder::unblock_commenter(&chall::ID, &author.pubkey(), &post, &commenter.pubkey());
+    env.process(&[unblock], &[&author]).await.unwrap();
+    env.add_comment(&commenter, &post, 1).await.unwrap();
+    env.process(&[edit_comment(&commenter, &post, 0)], &[&commenter])
+        .await
+        .unwrap();
+
+    let (comment, _) = find_comment_address(&chall::ID, &post, &commenter.pubkey(), 0);
+    match env.data(&comment).await {
+        SologData::Comment(comment) => assert_eq!(comment.content, b"edited"),
+        data => panic!("not a comment: {data:?}"),
+    }
+}
+
+#[tokio::test]
+async fn block_list_is_capped() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let post = env.create_post(&author, "blocking").await;
+
+    let commenters: Vec<_> = (0..=MAX_BLOCKED_COMMENTERS)
+        .map(|_| Pubkey::new_unique())
+        .collect();
+    for chunk in commenters[..MAX_BLOCKED_COMMENTERS].chunks(8) {
+        let blocks: Vec<_> = chunk
+            .iter()
+            .map(|commenter| block(&author, &post, commenter))
+            .collect();
+        env.process(&blocks, &[&author]).await.unwrap();
+    }
+
+    let last = block(&author, &post, &commenters[MAX_BLOCKED_COMMENTERS]);
+    assert_eq!(
+        instruction_error(env.process(&[last], &[&author]).await),
+        InstructionError::InvalidInstructionData
+    );
+
+    let (block_list, _) = find_block_list_address(&chall::ID, &post);
+    match env.data(&block_list).await {
+        SologData::BlockList(block_list) => {
+            assert_eq!(block_list.blocked_count as usize, MAX_BLOCKED_COMMENTERS);
+            assert_eq!(block_list.blocked[..], commenters[..MAX_BLOCKED_COMMENTERS]);
+        }
+        data => panic!("not a block list: {data:?}"),
+    }
+}
@@ -0,0 +1,216 @@
+//! Program test environment shared by the integration tests, running solog
+//! as a native program
+
+#![allow(dead_code)]
+
+use borsh::BorshDeserialize;
+use chall::{
+    builder,
+    instruction::{find_post_address, find_program_data_address},
+    processor::SologData,
+};
+use solana_program_test::{processor, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    bpf_loader_upgradeable::{self, UpgradeableLoaderState},
+    clock::Clock,
+    instruction::{Instruction, InstructionError},
+    native_token::LAMPORTS_PER_SOL,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    system_instruction,
+    transaction::{Transaction, TransactionError},
+};
+
+pub struct Env {
+    pub ctx: ProgramTestContext,
+    /// Upgrade authority of the program, the only key allowed to initialize
+    /// the config
+    pub admin: Keypair,
+}
+
+pub fn program_test() -> ProgramTest {
+    ProgramTest::new("chall", chall::ID, processor!(chall::process_instruction))
+}
+
+impl Env {
+    pub async fn start() -> Self {
+        Self::start_with(program_test()).await
+    }
+
+    /// Start `program_test`, registering an upgrade authority for solog
+    pub async fn start_with(mut program_test: ProgramTest) -> Self {
+        let admin = Keypair::new();
+        program_test.add_account(
+            admin.pubkey(),
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                ..Account::default()
+            },
+        );
+
+        let (program_data, _) = find_program_data_address(&chall::ID);
+        program_test.add_account(
+            program_data,
+            Account {
+                lamports: LAMPORTS_PER_SOL,
+                data: bincode::serialize(&UpgradeableLoaderState::ProgramData {
+                    slot: 0,
+                    upgrade_authority_address: Some(admin.pubkey()),
+                })
+                .unwrap(),
+                owner: bpf_loader_upgradeable::id(),
+                ..Account::default()
+            },
+        );
+
+        Self {
+            ctx: program_test.start_with_context().await,
+            admin,
+        }
+    }
+
+    /// Process `instructions` in one transaction paid by the context payer
+    pub async fn process(
+        &mut self,
+        instructions: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<(), BanksClientError> {
+        // a fresh blockhash so that repeated instructions are not deduplicated
+        let blockhash = self.ctx.get_new_latest_blockhash().await?;
+
+        let mut all_signers = vec![&self.ctx.payer];
+        all_signers.extend(signers);
+        let transaction = Transaction::new_signed_with_payer(
+            instructions,
+            Some(&self.ctx.payer.pubkey()),
+            &all_signers,
+            blockhash,
+        );
+
+        self.ctx.banks_client.process_transaction(transaction).await
+    }
+
+    pub async fn funded_keypair(&mut self) -> Keypair {
+        let keypair = Keypair::new();
+        self.transfer(&keypair.pubkey(), LAMPORTS_PER_SOL).await;
+        keypair
+    }
+
+    pub async fn transfer(&mut self, to: &Pubkey, lamports: u64) {
+        let payer = self.ctx.payer.pubkey();
+        self.process(&[system_instruction::transfer(&payer, to, lamports)], &[])
+            .await
+            .unwrap();
+    }
+
+    pub async fn init_config(&mut self, comment_slot_window: u64, flag_threshold: u32) {
+        let admin = self.admin.insecure_clone();
+        self.process(
+            &[builder::init_config(
+                &chall::ID,
+                &admin.pubkey(),
+                comment_slot_window,
+                flag_threshold,
+            )],
+            &[&admin],
+        )
+        .await
+        .unwrap();
+    }
+
+    pub async fn create_post(&mut self, author: &Keypair, title: &str) -> Pubkey {
+        self.process(
+            &[builder::create_post(
+                &chall::ID,
+                &author.pubkey(),
+                title.as_bytes().to_vec(),
+                b"content".to_vec(),
+            )],
+            &[author],
+        )
+        .await
+        .unwrap();
+
+        find_post_address(&chall::ID, &author.pubkey(), title.as_bytes()).0
+    }
+
+    pub async fn add_collaborator(
+        &mut self,
+        author: &Keypair,
+        collaborator: &Pubkey,
+        post: &Pubkey,
+    ) {
+        self.process(
+            &[builder::add_collaborator(
+                &chall::ID,
+                &author.pubkey(),
+                collaborator,
+                post,
+            )],
+            &[author],
+        )
+        .await
+        .unwrap();
+    }
+
+    pub async fn add_comment(
+        &mut self,
+        author: &Keypair,
+        post: &Pubkey,
+        order: u8,
+    ) -> Result<(), BanksClientError> {
+        self.process(
+            &[builder::add_comment(
+                &chall::ID,
+                &author.pubkey(),
+                post,
+                order,
+                b"comment".to_vec(),
+            )],
+            &[author],
+        )
+        .await
+    }
+
+    pub async fn account(&mut self, address: &Pubkey) -> Option<Account> {
+        self.ctx.banks_client.get_account(*address).await.unwrap()
+    }
+
+    pub async fn lamports(&mut self, address: &Pubkey) -> u64 {
+        self.account(address)
+            .await
+            .map_or(0, |account| account.lamports)
+    }
+
+    pub async fn data(&mut self, address: &Pubkey) -> SologData {
+        let account = self.account(address).await.expect("account not found");
+        SologData::deserialize(&mut &account.data[..]).unwrap()
+    }
+
+    pub async fn now(&mut self) -> i64 {
+        let clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp
+    }
+
+    /// Move the clock `seconds` forward, leaving the slot as it is
+    pub async fn warp_time(&mut self, seconds: i64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.unwrap();
+        clock.unix_timestamp += seconds;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    /// Move the bank `slots` slots past the current root
+    pub async fn warp_slots(&mut self, slots: u64) {
+        let slot = self.ctx.banks_client.get_root_slot().await.unwrap();
+        self.ctx.warp_to_slot(slot + slots).unwrap();
+    }
+}
+
+/// Error of the failed instruction of a transaction
+pub fn instruction_error(result: Result<(), BanksClientError>) -> InstructionError {
+    match result.expect_err("transaction succeeded").unwrap() {
+        TransactionError::InstructionError(_, err) => err,
+        err => panic!("unexpected transaction error: {err:?}"),
+    }
+}
@@ -0,0 +1,67 @@
+mod common;
+
+use chall::{
+    builder,
+    instruction::{find_comment_address, find_rate_limit_address},
+    processor::SologData,
+};
+use common::{instruction_error, Env};
+use solana_sdk::{instruction::InstructionError, rent::Rent, signature::Signer};
+
+const COMMENT_SLOT_WINDOW: u64 = 10;
+
+#[tokio::test]
+async fn only_the_upgrade_authority_initializes_the_config() {
+    let mut env = Env::start().await;
+    let intruder = env.funded_keypair().await;
+
+    let result = env
+        .process(
+            &[builder::init_config(&chall::ID, &intruder.pubkey(), 0, 0)],
+            &[&intruder],
+        )
+        .await;
+    assert_eq!(instruction_error(result), InstructionError::InvalidAccountData);
+
+    env.init_config(COMMENT_SLOT_WINDOW, 0).await;
+}
+
+#[tokio::test]
+async fn comments_wait_for_the_slot_window() {
+    let mut env = Env::start().await;
+    env.init_config(COMMENT_SLOT_WINDOW, 0).await;
+
+    let author = env.funded_keypair().await;
+    let other = env.funded_keypair().await;
+    let post = env.create_post(&author, "rate limited").await;
+
+    env.add_comment(&author, &post, 0).await.unwrap();
+    assert_eq!(
+        instruction_error(env.add_comment(&author, &post, 1).await),
+        InstructionError::InvalidInstructionData
+    );
+
+    // the window is per author
+    env.add_comment(&other, &post, 1).await.unwrap();
+
+    env.warp_slots(COMMENT_SLOT_WINDOW + 1).await;
+    env.add_comment(&author, &post, 2).await.unwrap();
+}
+
+#[tokio::test]
+async fn pre_funded_pdas_do_not_block_comments() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let post = env.create_post(&author, "pre funded").await;
+
+    let (rate_limit, _) = find_rate_limit_address(&chall::ID, &post, &author.pubkey());
+    let (comment, _) = find_comment_address(&chall::ID, &post, &author.pubkey(), 0);
+    let lamports = Rent::default().minimum_balance(0);
+    env.transfer(&rate_limit, lamports).await;
+    env.transfer(&comment, lamports).await;
+
+    env.add_comment(&author, &post, 0).await.unwrap();
+
+    assert!(matches!(env.data(&rate_limit).await, SologData::RateLimit(_)));
+    assert!(matches!(env.data(&comment).await, SologData::Comment(_)));
+}
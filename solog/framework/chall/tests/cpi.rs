@@ -0,0 +1,127 @@
+mod common;
+
+use chall::{
+    cpi,
+    instruction::{
+        find_block_list_address, find_comment_address, find_config_address, find_post_address,
+        find_rate_limit_address,
+    },
+    processor::SologData,
+};
+use common::{program_test, Env};
+use solana_program_test::processor;
+use solana_sdk::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program_error::ProgramError,
+    pubkey::Pubkey,
+    signature::Signer,
+    system_program,
+};
+
+const AUTHOR_SEED: &[u8] = b"author";
+const TITLE: &[u8] = b"from a program";
+
+/// Posts and comments through solog as a pda it signs for, the rent being
+/// paid by the signer of the transaction
+fn caller(program_id: &Pubkey, accounts: &[AccountInfo], data: &[u8]) -> ProgramResult {
+    let [solog, payer, author, post, system_program, rest @ ..] = accounts else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+    let (_, author_seed) = Pubkey::find_program_address(&[AUTHOR_SEED], program_id);
+    let signer_seeds: &[&[&[u8]]] = &[&[AUTHOR_SEED, &[author_seed]]];
+
+    match data {
+        [0, title @ ..] => cpi::create_post(
+            solog,
+            cpi::CreatePost {
+                author: author.clone(),
+                post: post.clone(),
+                system_program: system_program.clone(),
+                payer: Some(payer.clone()),
+            },
+            title.to_vec(),
+            b"posted through cpi".to_vec(),
+            signer_seeds,
+        ),
+        [1] => {
+            let [comment, block_list, config, rate_limit] = rest else {
+                return Err(ProgramError::NotEnoughAccountKeys);
+            };
+
+            let summary = cpi::get_post_summary(solog, cpi::GetPostSummary { post: post.clone() })?;
+            if summary.author != *author.key {
+                return Err(ProgramError::InvalidAccountData);
+            }
+
+            cpi::add_comment(
+                solog,
+                cpi::AddComment {
+                    author: author.clone(),
+                    post: post.clone(),
+                    comment: comment.clone(),
+                    system_program: system_program.clone(),
+                    block_list: block_list.clone(),
+                    config: config.clone(),
+                    rate_limit: rate_limit.clone(),
+                    payer: Some(payer.clone()),
+                },
+                summary.comment_count,
+                b"commented through cpi".to_vec(),
+                signer_seeds,
+            )
+        }
+        _ => Err(ProgramError::InvalidInstructionData),
+    }
+}
+
+#[tokio::test]
+async fn program_posts_and_comments_as_its_pda() {
+    let caller_id = Pubkey::new_unique();
+    let mut program_test = program_test();
+    program_test.add_program("caller", caller_id, processor!(caller));
+    let mut env = Env::start_with(program_test).await;
+
+    let payer = env.funded_keypair().await;
+    let (author, _) = Pubkey::find_program_address(&[AUTHOR_SEED], &caller_id);
+    let (post, _) = find_post_address(&chall::ID, &author, TITLE);
+    let (comment, _) = find_comment_address(&chall::ID, &post, &author, 0);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(chall::ID, false),
+        AccountMeta::new(payer.pubkey(), true),
+        AccountMeta::new(author, false),
+        AccountMeta::new(post, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+    ];
+    let create_post = Instruction::new_with_bytes(
+        caller_id,
+        &[&[0], TITLE].concat(),
+        accounts.clone(),
+    );
+    env.process(&[create_post], &[&payer]).await.unwrap();
+
+    accounts.extend([
+        AccountMeta::new(comment, false),
+        AccountMeta::new_readonly(find_block_list_address(&chall::ID, &post).0, false),
+        AccountMeta::new_readonly(find_config_address(&chall::ID).0, false),
+        AccountMeta::new(find_rate_limit_address(&chall::ID, &post, &author).0, false),
+    ]);
+    let add_comment = Instruction::new_with_bytes(caller_id, &[1], accounts);
+    env.process(&[add_comment], &[&payer]).await.unwrap();
+
+    match env.data(&post).await {
+        SologData::Post(post) => {
+            assert_eq!(post.author, author);
+            assert_eq!(post.comment_count, 1);
+        }
+        data => panic!("not a post: {data:?}"),
+    }
+    match env.data(&comment).await {
+        SologData::Comment(comment) => assert_eq!(comment.author, author),
+        data => panic!("not a comment: {data:?}"),
+    }
+    // the pda never held lamports, the payer funded every account
+    assert_eq!(env.lamports(&author).await, 0);
+}
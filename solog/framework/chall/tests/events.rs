@@ -0,0 +1,72 @@
+//! The events are decoded from the log lines the runtime writes for
+//! `sol_log_data`. Native program tests only print them, so the syscall is
+//! stubbed here to record them with the runtime's own formatting.
+
+use std::sync::{Arc, Mutex};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use chall::{event::SologEvent, processor::HeapChunk};
+use solana_program_runtime::{log_collector::LogCollector, stable_log};
+use solana_sdk::{
+    program_stubs::{set_syscall_stubs, SyscallStubs},
+    pubkey::Pubkey,
+};
+
+struct RecordLogs(Arc<Mutex<Vec<String>>>);
+
+impl SyscallStubs for RecordLogs {
+    fn sol_log_data(&self, fields: &[&[u8]]) {
+        let log_collector = Some(LogCollector::new_ref());
+        stable_log::program_data(&log_collector, fields);
+
+        let log_collector = log_collector.unwrap();
+        let recorded = log_collector.borrow().get_recorded_content().to_vec();
+        self.0.lock().unwrap().extend(recorded);
+    }
+}
+
+#[test]
+fn emitted_events_roundtrip_through_program_data_logs() {
+    let logs = Arc::new(Mutex::new(vec![
+        "Program so1og11111111111111111111111111111111111111 invoke [1]".to_string(),
+        "Program log: not an event".to_string(),
+    ]));
+    set_syscall_stubs(Box::new(RecordLogs(logs.clone())));
+
+    let events = [
+        SologEvent::PostCreated {
+            post: Pubkey::new_unique(),
+            author: Pubkey::new_unique(),
+        },
+        SologEvent::HeapDumped {
+            chunks: vec![HeapChunk {
+                index: 1,
+                address: 0x3_0000_0010,
+                length: 24,
+                next: 0x3_0000_0030,
+            }],
+        },
+        SologEvent::PostExpirySet {
+            post: Pubkey::new_unique(),
+            expires_at: None,
+        },
+    ];
+    for event in &events {
+        event.emit().unwrap();
+    }
+
+    let logs = logs.lock().unwrap();
+    assert!(logs[2].starts_with("Program data: "));
+    assert_eq!(SologEvent::from_logs(&logs), events);
+}
+
+#[test]
+fn other_program_data_is_skipped() {
+    let logs = [
+        "Program data: not base64".to_string(),
+        format!("Program data: {}", STANDARD.encode([0xff; 8])),
+        format!("Program log: {}", STANDARD.encode([0; 65])),
+    ];
+
+    assert!(SologEvent::from_logs(&logs).is_empty());
+}
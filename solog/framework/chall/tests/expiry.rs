@@ -0,0 +1,231 @@
+mod common;
+
+use chall::{
+    builder,
+    instruction::{find_bounty_address, find_comment_address, find_post_address},
+    processor::SologData,
+};
+use common::{instruction_error, Env};
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const BOUNTY: u64 = 100_000_000;
+
+fn set_expiry(author: &Keypair, post: &Pubkey, expires_at: Option<i64>) -> Instruction {
+    builder::set_post_expiry(&chall::ID, &author.pubkey(), post, expires_at)
+}
+
+#[tokio::test]
+async fn expiry_is_set_in_the_future_only() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let post = env.create_post(&author, "expiring").await;
+
+    let now = env.now().await;
+    assert_eq!(
+        instruction_error(env.process(&[set_expiry(&author, &post, Some(now))], &[&author]).await),
+        InstructionError::InvalidInstructionData
+    );
+
+    env.process(&[set_expiry(&author, &post, Some(now + 100))], &[&author])
+        .await
+        .unwrap();
+    env.warp_time(100).await;
+
+    // an expired post can neither be extended nor revived
+    let now = env.now().await;
+    for expires_at in [Some(now + 100), None] {
+        assert_eq!(
+            instruction_error(
+                env.process(&[set_expiry(&author, &post, expires_at)], &[&author])
+                    .await
+            ),
+            InstructionError::InvalidInstructionData
+        );
+    }
+}
+
+#[tokio::test]
+async fn closing_an_expired_post_refunds_its_bounty() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let commenter = env.funded_keypair().await;
+    let post = env.create_post(&author, "expiring").await;
+    env.add_comment(&commenter, &post, 0).await.unwrap();
+    let (comment, _) = find_comment_address(&chall::ID, &post, &commenter.pubkey(), 0);
+
+    let now = env.now().await;
+    env.process(
+        &[
+            builder::create_bounty(&chall::ID, &author.pubkey(), &post, BOUNTY, now + 3600),
+            set_expiry(&author, &post, Some(now + 100)),
+        ],
+        &[&author],
+    )
+    .await
+    .unwrap();
+    env.warp_time(100).await;
+
+    let (bounty, _) = find_bounty_address(&chall::ID, &post);
+    let vault = env.lamports(&bounty).await;
+    let post_rent = env.lamports(&post).await;
+    let balance = env.lamports(&author.pubkey()).await;
+
+    let close = builder::close_expired(
+        &chall::ID,
+        &post,
+        &author.pubkey(),
+        &author.pubkey(),
+        &[(comment, commenter.pubkey())],
+    );
+    env.process(&[close], &[]).await.unwrap();
+
+    assert!(env.account(&post).await.is_none());
+    assert!(env.account(&bounty).await.is_none());
+    assert_eq!(env.lamports(&author.pubkey()).await, balance + post_rent + vault);
+}
+
+#[tokio::test]
+async fn bounty_stays_while_comments_are_left() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let commenter = env.funded_keypair().await;
+    let post = env.create_post(&author, "expiring").await;
+    env.add_comment(&commenter, &post, 0).await.unwrap();
+
+    let now = env.now().await;
+    env.process(
+        &[
+            builder::create_bounty(&chall::ID, &author.pubkey(), &post, BOUNTY, now + 3600),
+            set_expiry(&author, &post, Some(now + 100)),
+        ],
+        &[&author],
+    )
+    .await
+    .unwrap();
+    env.warp_time(100).await;
+
+    let (bounty, _) = find_bounty_address(&chall::ID, &post);
+    let vault = env.lamports(&bounty).await;
+    let close =
+        builder::close_expired(&chall::ID, &post, &author.pubkey(), &author.pubkey(), &[]);
+    env.process(&[close], &[]).await.unwrap();
+
+    assert!(env.account(&post).await.is_some());
+    assert_eq!(env.lamports(&bounty).await, vault);
+}
+
+#[tokio::test]
+async fn rent_goes_back_to_whoever_paid_it() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let commenter = env.funded_keypair().await;
+    let payer = env.funded_keypair().await;
+
+    env.process(
+        &[builder::with_payer(
+            builder::create_post(
+                &chall::ID,
+                &author.pubkey(),
+                b"sponsored".to_vec(),
+                b"content".to_vec(),
+            ),
+            &payer.pubkey(),
+        )],
+        &[&author, &payer],
+    )
+    .await
+    .unwrap();
+    let (post, _) = find_post_address(&chall::ID, &author.pubkey(), b"sponsored");
+
+    for order in 0..2 {
+        env.process(
+            &[builder::with_payer(
+                builder::add_comment(
+                    &chall::ID,
+                    &commenter.pubkey(),
+                    &post,
+                    order,
+                    b"comment".to_vec(),
+                ),
+                &payer.pubkey(),
+            )],
+            &[&commenter, &payer],
+        )
+        .await
+        .unwrap();
+    }
+    let comments =
+        [0, 1].map(|order| find_comment_address(&chall::ID, &post, &commenter.pubkey(), order).0);
+
+    let now = env.now().await;
+    env.process(&[set_expiry(&author, &post, Some(now + 100))], &[&author])
+        .await
+        .unwrap();
+    env.warp_time(100).await;
+
+    // the comment author did not pay for the comment
+    let close = builder::close_expired(
+        &chall::ID,
+        &post,
+        &author.pubkey(),
+        &payer.pubkey(),
+        &[(comments[0], commenter.pubkey())],
+    );
+    assert_eq!(
+        instruction_error(env.process(&[close], &[]).await),
+        InstructionError::InvalidAccountData
+    );
+
+    let rent = env.lamports(&comments[0]).await;
+    let balance = env.lamports(&payer.pubkey()).await;
+    let close = builder::close_expired(
+        &chall::ID,
+        &post,
+        &author.pubkey(),
+        &payer.pubkey(),
+        &[(comments[0], payer.pubkey())],
+    );
+    env.process(&[close], &[]).await.unwrap();
+    assert_eq!(env.lamports(&payer.pubkey()).await, balance + rent);
+
+    // closed comments are counted apart, the comment count still gives the
+    // order of the next comment
+    match env.data(&post).await {
+        SologData::Post(post) => {
+            assert_eq!(post.comment_count, 2);
+            assert_eq!(post.closed_comments, 1);
+        }
+        data => panic!("not a post: {data:?}"),
+    }
+
+    // the post is refunded to its payer, not to its author
+    let close = builder::close_expired(
+        &chall::ID,
+        &post,
+        &author.pubkey(),
+        &author.pubkey(),
+        &[(comments[1], payer.pubkey())],
+    );
+    assert_eq!(
+        instruction_error(env.process(&[close], &[]).await),
+        InstructionError::InvalidAccountData
+    );
+
+    let rent = env.lamports(&comments[1]).await + env.lamports(&post).await;
+    let balance = env.lamports(&payer.pubkey()).await;
+    let close = builder::close_expired(
+        &chall::ID,
+        &post,
+        &author.pubkey(),
+        &payer.pubkey(),
+        &[(comments[1], payer.pubkey())],
+    );
+    env.process(&[close], &[]).await.unwrap();
+
+    assert!(env.account(&post).await.is_none());
+    assert_eq!(env.lamports(&payer.pubkey()).await, balance + rent);
+}
@@ -0,0 +1,113 @@
+mod common;
+
+use chall::{
+    builder,
+    instruction::{find_comment_address, find_flag_address},
+    processor::SologData,
+};
+use common::{instruction_error, Env};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+async fn flag(env: &mut Env, flagger: &Keypair, target: &Pubkey) {
+    env.process(&[builder::flag(&chall::ID, &flagger.pubkey(), target)], &[flagger])
+        .await
+        .unwrap();
+}
+
+async fn flags(env: &mut Env, target: &Pubkey) -> (u32, bool) {
+    match env.data(target).await {
+        SologData::Post(post) => (post.flags, post.hidden),
+        SologData::Comment(comment) => (comment.flags, comment.hidden),
+        data => panic!("not a post or comment: {data:?}"),
+    }
+}
+
+#[tokio::test]
+async fn comments_are_unhidden_by_post_moderators() {
+    let mut env = Env::start().await;
+    env.init_config(0, 1).await;
+    let author = env.funded_keypair().await;
+    let commenter = env.funded_keypair().await;
+    let flagger = env.funded_keypair().await;
+    let post = env.create_post(&author, "flagged").await;
+    env.add_comment(&commenter, &post, 0).await.unwrap();
+    let (comment, _) = find_comment_address(&chall::ID, &post, &commenter.pubkey(), 0);
+
+    flag(&mut env, &flagger, &comment).await;
+    assert_eq!(flags(&mut env, &comment).await, (1, true));
+
+    let unhide = |reviewer: &Keypair| {
+        builder::unhide(&chall::ID, &reviewer.pubkey(), &post, &comment, &[flagger.pubkey()])
+    };
+    assert_eq!(
+        instruction_error(env.process(&[unhide(&commenter)], &[&commenter]).await),
+        InstructionError::InvalidAccountData
+    );
+    assert_eq!(flags(&mut env, &comment).await, (1, true));
+
+    env.process(&[unhide(&author)], &[&author]).await.unwrap();
+    assert_eq!(flags(&mut env, &comment).await, (0, false));
+    let (flag_pda, _) = find_flag_address(&chall::ID, &comment, &flagger.pubkey());
+    assert!(env.account(&flag_pda).await.is_none());
+}
+
+#[tokio::test]
+async fn comment_must_belong_to_the_reviewed_post() {
+    let mut env = Env::start().await;
+    env.init_config(0, 1).await;
+    let author = env.funded_keypair().await;
+    let commenter = env.funded_keypair().await;
+    let flagger = env.funded_keypair().await;
+    let post = env.create_post(&author, "flagged").await;
+    env.add_comment(&commenter, &post, 0).await.unwrap();
+    let (comment, _) = find_comment_address(&chall::ID, &post, &commenter.pubkey(), 0);
+    flag(&mut env, &flagger, &comment).await;
+
+    // the commenter moderates a post of their own, not the commented one
+    let own_post = env.create_post(&commenter, "own").await;
+    let unhide = builder::unhide(&chall::ID, &commenter.pubkey(), &own_post, &comment, &[]);
+    assert_eq!(
+        instruction_error(env.process(&[unhide], &[&commenter]).await),
+        InstructionError::InvalidAccountData
+    );
+    assert_eq!(flags(&mut env, &comment).await, (1, true));
+}
+
+#[tokio::test]
+async fn unhidden_posts_can_be_flagged_again() {
+    let mut env = Env::start().await;
+    env.init_config(0, 2).await;
+    let author = env.funded_keypair().await;
+    let first = env.funded_keypair().await;
+    let second = env.funded_keypair().await;
+    let post = env.create_post(&author, "flagged").await;
+
+    flag(&mut env, &first, &post).await;
+    flag(&mut env, &second, &post).await;
+    assert_eq!(flags(&mut env, &post).await, (2, true));
+
+    // only the reviewed flag is cleared and refunded
+    let balance = env.lamports(&first.pubkey()).await;
+    let (first_flag, _) = find_flag_address(&chall::ID, &post, &first.pubkey());
+    let rent = env.lamports(&first_flag).await;
+    let unhide = builder::unhide(&chall::ID, &author.pubkey(), &post, &post, &[first.pubkey()]);
+    env.process(&[unhide], &[&author]).await.unwrap();
+    assert_eq!(flags(&mut env, &post).await, (1, false));
+    assert_eq!(env.lamports(&first.pubkey()).await, balance + rent);
+
+    flag(&mut env, &first, &post).await;
+    assert_eq!(flags(&mut env, &post).await, (2, true));
+
+    // the remaining flag stays, so its flagger can't flag twice
+    assert_eq!(
+        instruction_error(
+            env.process(&[builder::flag(&chall::ID, &second.pubkey(), &post)], &[&second])
+                .await
+        ),
+        InstructionError::InvalidInstructionData
+    );
+}
@@ -0,0 +1,137 @@
+mod common;
+
+use chall::{
+    builder,
+    instruction::{find_follow_address, find_profile_address},
+    processor::SologData,
+};
+use common::{instruction_error, Env};
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+fn follow(follower: &Keypair, following: &Pubkey) -> Instruction {
+    builder::follow(&chall::ID, &follower.pubkey(), following)
+}
+
+fn unfollow(follower: &Keypair, following: &Pubkey) -> Instruction {
+    builder::unfollow(&chall::ID, &follower.pubkey(), following)
+}
+
+/// Follower and following counts of the profile of `owner`
+async fn counts(env: &mut Env, owner: &Pubkey) -> (u32, u32) {
+    let (profile, _) = find_profile_address(&chall::ID, owner);
+    match env.data(&profile).await {
+        SologData::Profile(profile) => (profile.follower_count, profile.following_count),
+        data => panic!("not a profile: {data:?}"),
+    }
+}
+
+#[tokio::test]
+async fn follow_edges_are_counted_once() {
+    let mut env = Env::start().await;
+    let alice = env.funded_keypair().await;
+    let bob = env.funded_keypair().await;
+
+    env.process(&[follow(&alice, &bob.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    assert_eq!(counts(&mut env, &alice.pubkey()).await, (0, 1));
+    assert_eq!(counts(&mut env, &bob.pubkey()).await, (1, 0));
+
+    assert_eq!(
+        instruction_error(
+            env.process(&[follow(&alice, &bob.pubkey())], &[&alice])
+                .await
+        ),
+        InstructionError::InvalidAccountData
+    );
+    assert_eq!(counts(&mut env, &bob.pubkey()).await, (1, 0));
+
+    // following back is a separate edge
+    env.process(&[follow(&bob, &alice.pubkey())], &[&bob])
+        .await
+        .unwrap();
+    assert_eq!(counts(&mut env, &alice.pubkey()).await, (1, 1));
+    assert_eq!(counts(&mut env, &bob.pubkey()).await, (1, 1));
+}
+
+#[tokio::test]
+async fn unfollow_closes_the_edge() {
+    let mut env = Env::start().await;
+    let alice = env.funded_keypair().await;
+    let bob = env.funded_keypair().await;
+    let (edge, _) = find_follow_address(&chall::ID, &alice.pubkey(), &bob.pubkey());
+
+    env.process(&[follow(&alice, &bob.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    let rent = env.lamports(&edge).await;
+    let balance = env.lamports(&alice.pubkey()).await;
+
+    env.process(&[unfollow(&alice, &bob.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    assert!(env.account(&edge).await.is_none());
+    assert_eq!(env.lamports(&alice.pubkey()).await, balance + rent);
+    assert_eq!(counts(&mut env, &alice.pubkey()).await, (0, 0));
+    assert_eq!(counts(&mut env, &bob.pubkey()).await, (0, 0));
+
+    // the edge is gone, and so is the second unfollow
+    assert_eq!(
+        instruction_error(
+            env.process(&[unfollow(&alice, &bob.pubkey())], &[&alice])
+                .await
+        ),
+        InstructionError::InvalidAccountData
+    );
+
+    env.process(&[follow(&alice, &bob.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    assert_eq!(counts(&mut env, &bob.pubkey()).await, (1, 0));
+}
+
+#[tokio::test]
+async fn unfollow_needs_an_edge() {
+    let mut env = Env::start().await;
+    let alice = env.funded_keypair().await;
+    let bob = env.funded_keypair().await;
+    let carol = env.funded_keypair().await;
+
+    // both profiles exist, only not through an edge between them
+    env.process(&[follow(&alice, &carol.pubkey())], &[&alice])
+        .await
+        .unwrap();
+    env.process(&[follow(&bob, &carol.pubkey())], &[&bob])
+        .await
+        .unwrap();
+
+    assert_eq!(
+        instruction_error(
+            env.process(&[unfollow(&alice, &bob.pubkey())], &[&alice])
+                .await
+        ),
+        InstructionError::InvalidAccountData
+    );
+    assert_eq!(counts(&mut env, &alice.pubkey()).await, (0, 1));
+    assert_eq!(counts(&mut env, &bob.pubkey()).await, (0, 1));
+}
+
+#[tokio::test]
+async fn nobody_follows_themselves() {
+    let mut env = Env::start().await;
+    let alice = env.funded_keypair().await;
+
+    assert_eq!(
+        instruction_error(
+            env.process(&[follow(&alice, &alice.pubkey())], &[&alice])
+                .await
+        ),
+        InstructionError::InvalidInstructionData
+    );
+    let (profile, _) = find_profile_address(&chall::ID, &alice.pubkey());
+    assert!(env.account(&profile).await.is_none());
+}
@@ -0,0 +1,133 @@
+mod common;
+
+use chall::{builder, instruction::find_comment_address, processor::SologData};
+use common::{instruction_error, Env};
+use solana_sdk::{
+    instruction::{Instruction, InstructionError},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+fn pin(moderator: &Keypair, post: &Pubkey, comment: &Pubkey) -> Instruction {
+    builder::pin_comment(&chall::ID, &moderator.pubkey(), post, comment)
+}
+
+async fn pinned(env: &mut Env, post: &Pubkey) -> Vec<Pubkey> {
+    match env.data(post).await {
+        SologData::Post(post) => post.pinned_comments[..post.pinned_count as usize].to_vec(),
+        data => panic!("not a post: {data:?}"),
+    }
+}
+
+#[tokio::test]
+async fn collaborators_pin_once_made_moderators() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let collaborator = env.funded_keypair().await;
+    let post = env.create_post(&author, "pinned").await;
+    env.add_collaborator(&author, &collaborator.pubkey(), &post)
+        .await;
+    env.add_comment(&author, &post, 0).await.unwrap();
+    let (comment, _) = find_comment_address(&chall::ID, &post, &author.pubkey(), 0);
+
+    assert_eq!(
+        instruction_error(
+            env.process(&[pin(&collaborator, &post, &comment)], &[&collaborator])
+                .await
+        ),
+        InstructionError::InvalidAccountData
+    );
+
+    let set_moderator = |moderator| {
+        builder::set_moderator(
+            &chall::ID,
+            &author.pubkey(),
+            &collaborator.pubkey(),
+            &post,
+            moderator,
+        )
+    };
+    env.process(&[set_moderator(true)], &[&author])
+        .await
+        .unwrap();
+    env.process(&[pin(&collaborator, &post, &comment)], &[&collaborator])
+        .await
+        .unwrap();
+    assert_eq!(pinned(&mut env, &post).await, [comment]);
+
+    // revoked moderators can't unpin
+    env.process(&[set_moderator(false)], &[&author])
+        .await
+        .unwrap();
+    let unpin = builder::unpin_comment(&chall::ID, &collaborator.pubkey(), &post, &comment);
+    assert_eq!(
+        instruction_error(env.process(&[unpin], &[&collaborator]).await),
+        InstructionError::InvalidAccountData
+    );
+
+    let unpin = builder::unpin_comment(&chall::ID, &author.pubkey(), &post, &comment);
+    env.process(&[unpin], &[&author]).await.unwrap();
+    assert!(pinned(&mut env, &post).await.is_empty());
+}
+
+#[tokio::test]
+async fn only_collaborators_are_made_moderators() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let stranger = Pubkey::new_unique();
+    let post = env.create_post(&author, "pinned").await;
+
+    let set_moderator =
+        builder::set_moderator(&chall::ID, &author.pubkey(), &stranger, &post, true);
+    assert_eq!(
+        instruction_error(env.process(&[set_moderator], &[&author]).await),
+        InstructionError::InvalidAccountData
+    );
+}
+
+#[tokio::test]
+async fn comments_of_other_posts_are_not_pinned() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let commenter = env.funded_keypair().await;
+    let post = env.create_post(&author, "pinned").await;
+    let other = env.create_post(&commenter, "other").await;
+    env.add_comment(&commenter, &other, 0).await.unwrap();
+    let (comment, _) = find_comment_address(&chall::ID, &other, &commenter.pubkey(), 0);
+
+    assert_eq!(
+        instruction_error(
+            env.process(&[pin(&author, &post, &comment)], &[&author])
+                .await
+        ),
+        InstructionError::InvalidAccountData
+    );
+    assert!(pinned(&mut env, &post).await.is_empty());
+}
+
+#[tokio::test]
+async fn at_most_three_comments_are_pinned() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let post = env.create_post(&author, "pinned").await;
+
+    let mut comments = Vec::new();
+    for order in 0..4 {
+        env.add_comment(&author, &post, order).await.unwrap();
+        comments.push(find_comment_address(&chall::ID, &post, &author.pubkey(), order).0);
+    }
+
+    for comment in &comments[..3] {
+        env.process(&[pin(&author, &post, comment)], &[&author])
+            .await
+            .unwrap();
+    }
+    assert_eq!(
+        instruction_error(
+            env.process(&[pin(&author, &post, &comments[3])], &[&author])
+                .await
+        ),
+        InstructionError::InvalidInstructionData
+    );
+    assert_eq!(pinned(&mut env, &post).await, comments[..3]);
+}
@@ -0,0 +1,217 @@
+mod common;
+
+use chall::{builder, instruction::find_proposal_address, processor::SologData};
+use common::{instruction_error, Env};
+use solana_sdk::{
+    instruction::InstructionError,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+#[tokio::test]
+async fn collaborator_edits_need_the_author() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let collaborator = env.funded_keypair().await;
+    let post = env.create_post(&author, "shared").await;
+
+    env.process(
+        &[builder::add_collaborator(
+            &chall::ID,
+            &author.pubkey(),
+            &collaborator.pubkey(),
+            &post,
+        )],
+        &[&author],
+    )
+    .await
+    .unwrap();
+    env.process(
+        &[builder::propose_edit(
+            &chall::ID,
+            &collaborator.pubkey(),
+            &post,
+            b"rewritten".to_vec(),
+        )],
+        &[&collaborator],
+    )
+    .await
+    .unwrap();
+
+    // the proposal already meets the default threshold of one approval
+    let execute = builder::execute_proposal(
+        &chall::ID,
+        &collaborator.pubkey(),
+        &post,
+        &collaborator.pubkey(),
+    );
+    assert_eq!(
+        instruction_error(env.process(&[execute.clone()], &[&collaborator]).await),
+        InstructionError::InvalidInstructionData
+    );
+
+    env.process(
+        &[builder::approve_proposal(
+            &chall::ID,
+            &author.pubkey(),
+            &post,
+            &collaborator.pubkey(),
+        )],
+        &[&author],
+    )
+    .await
+    .unwrap();
+    env.process(&[execute], &[&collaborator]).await.unwrap();
+
+    match env.data(&post).await {
+        SologData::Post(post) => assert_eq!(post.content, b"rewritten"),
+        data => panic!("not a post: {data:?}"),
+    }
+}
+
+#[tokio::test]
+async fn threshold_alone_when_the_author_is_not_required() {
+    let mut env = Env::start().await;
+    let author = env.funded_keypair().await;
+    let first = env.funded_keypair().await;
+    let second = env.funded_keypair().await;
+    let post = env.create_post(&author, "shared").await;
+    env.add_collaborator(&author, &first.pubkey(), &post).await;
+    env.add_collaborator(&author, &second.pubkey(), &post).await;
+
+    env.process(
+        &[builder::set_approval_threshold(
+            &chall::ID,
+            &author.pubkey(),
+            &post,
+            2,
+            false,
+        )],
+        &[&author],
+    )
+    .await
+    .unwrap();
+    env.process(
+        &[builder::propose_edit(
+            &chall::ID,
+            &first.pubkey(),
+            &post,
+            b"rewritten".to_vec(),
+        )],
+        &[&first],
+    )
+    .await
+    .unwrap();
+
+    let execute =
+        builder::execute_proposal(&chall::ID, &first.pubkey(), &post, &first.pubkey());
+    assert_eq!(
+        instruction_error(env.process(&[execute.clone()], &[&first]).await),
+        InstructionError::InvalidInstructionData
+    );
+
+    env.process(
+        &[builder::approve_proposal(
+            &chall::ID,
+            &second.pubkey(),
+            &post,
+            &first.pubkey(),
+        )],
+        &[&second],
+    )
+    .await
+    .unwrap();
+    env.process(&[execute], &[&first]).await.unwrap();
+
+    match env.data(&post).await {
+        SologData::Post(post) => assert_eq!(post.content, b"rewritten"),
+        data => panic!("not a post: {data:?}"),
+    }
+}
+
+async fn pending_proposal(env: &mut Env) -> (Keypair, Keypair, Pubkey) {
+    let author = env.funded_keypair().await;
+    let collaborator = env.funded_keypair().await;
+    let post = env.create_post(&author, "shared").await;
+    env.add_collaborator(&author, &collaborator.pubkey(), &post).await;
+
+    env.process(
+        &[builder::propose_edit(
+            &chall::ID,
+            &collaborator.pubkey(),
+            &post,
+            b"rewritten".to_vec(),
+        )],
+        &[&collaborator],
+    )
+    .await
+    .unwrap();
+
+    (author, collaborator, post)
+}
+
+#[tokio::test]
+async fn proposer_cancels_and_gets_the_rent_back() {
+    let mut env = Env::start().await;
+    let (_, collaborator, post) = pending_proposal(&mut env).await;
+    let (proposal, _) = find_proposal_address(&chall::ID, &post, &collaborator.pubkey());
+    let rent = env.lamports(&proposal).await;
+    let before = env.lamports(&collaborator.pubkey()).await;
+
+    env.process(
+        &[builder::cancel_proposal(
+            &chall::ID,
+            &collaborator.pubkey(),
+            &post,
+            &collaborator.pubkey(),
+        )],
+        &[&collaborator],
+    )
+    .await
+    .unwrap();
+
+    assert!(env.account(&proposal).await.is_none());
+    assert_eq!(env.lamports(&collaborator.pubkey()).await, before + rent);
+}
+
+#[tokio::test]
+async fn author_cancels_stale_proposals() {
+    let mut env = Env::start().await;
+    let (author, collaborator, post) = pending_proposal(&mut env).await;
+    let (proposal, _) = find_proposal_address(&chall::ID, &post, &collaborator.pubkey());
+    let rent = env.lamports(&proposal).await;
+    let before = env.lamports(&collaborator.pubkey()).await;
+
+    env.process(
+        &[builder::cancel_proposal(
+            &chall::ID,
+            &author.pubkey(),
+            &post,
+            &collaborator.pubkey(),
+        )],
+        &[&author],
+    )
+    .await
+    .unwrap();
+
+    assert!(env.account(&proposal).await.is_none());
+    assert_eq!(env.lamports(&collaborator.pubkey()).await, before + rent);
+}
+
+#[tokio::test]
+async fn others_can_not_cancel() {
+    let mut env = Env::start().await;
+    let (_, collaborator, post) = pending_proposal(&mut env).await;
+    let stranger = env.funded_keypair().await;
+
+    let cancel = builder::cancel_proposal(
+        &chall::ID,
+        &stranger.pubkey(),
+        &post,
+        &collaborator.pubkey(),
+    );
+    assert_eq!(
+        instruction_error(env.process(&[cancel], &[&stranger]).await),
+        InstructionError::InvalidAccountData
+    );
+}
@@ -1,5 +1,5 @@
 # > solana --version
 # solana-cli 1.18.1 (src:5d824a36; feat:756280933, client:SolanaLabs)
 
-cd chall/ && cargo build-bpf && cd ..
+cd chall/ && cargo build-bpf --features dev-tools && cd ..
 cargo run
\ No newline at end of file
@@ -1,17 +1,20 @@
 use std::env;
 use std::io::{BufReader, Read, Write};
 
-use borsh::{to_vec, BorshDeserialize};
-use chall::processor::{SologData, SologInstruction};
+use borsh::BorshDeserialize;
+use chall::builder;
+use chall::instruction::find_post_address;
+use chall::processor::SologData;
 use sha2::Sha256;
 use sol_ctf_framework::ChallengeBuilder;
 
-use solana_program::instruction::{AccountMeta, Instruction};
-use solana_program::{system_instruction, system_program};
+use solana_program::instruction::Instruction;
+use solana_program::system_instruction;
 use solana_program_test::tokio;
 use solana_sdk::pubkey::{self, Pubkey};
 use solana_sdk::signature::Signer;
 use solana_sdk::signer::keypair::Keypair;
+use solana_sdk::transaction::Transaction;
 use std::error::Error;
 use rand::distributions::Alphanumeric;
 use rand::Rng;
@@ -130,25 +133,14 @@ async fn handle_connection(mut socket: TcpStream) -> Result<(), Box<dyn Error>>
     let title = "Hello World";
     let content = "This is first post for solog!";
 
-    let create_post_ix = SologInstruction::CreatePost {
-        title: title.as_bytes().to_vec(),
-        content: content.as_bytes().to_vec(),
-    };
+    let (post, _) = find_post_address(&chall::ID, &admin, title.as_bytes());
 
-    let (post, _) = Pubkey::find_program_address(
-        &[b"post", &admin.to_bytes(), title.as_bytes()],
+    chall.run_ix(builder::create_post(
         &chall::ID,
-    );
-
-    chall.run_ix(Instruction {
-        program_id: chall::ID,
-        accounts: vec![
-            AccountMeta::new(admin, true),
-            AccountMeta::new(post, false),
-            AccountMeta::new_readonly(system_program::id(), false),
-        ],
-        data: to_vec(&create_post_ix)?,
-    }).await?;
+        &admin,
+        title.as_bytes().to_vec(),
+        content.as_bytes().to_vec(),
+    )).await?;
 
     writeln!(socket, "user: {}", user)?;
     writeln!(socket, "post: {}", post)?;
@@ -167,9 +159,22 @@ async fn handle_connection(mut socket: TcpStream) -> Result<(), Box<dyn Error>>
             return Ok(());
         }
 
-        chall
-            .run_ixs_full(&[solve_ix], &[&user_keypair], &user)
+        let blockhash = chall.ctx.banks_client.get_latest_blockhash().await?;
+        let transaction =
+            Transaction::new_signed_with_payer(&[solve_ix], Some(&user), &[&user_keypair], blockhash);
+        let result = chall
+            .ctx
+            .banks_client
+            .process_transaction_with_metadata(transaction)
             .await?;
+
+        // echo the logs so that clients can decode the solog events
+        if let Some(metadata) = result.metadata {
+            for log in metadata.log_messages {
+                writeln!(socket, "{}", log)?;
+            }
+        }
+        result.result?;
     }
 
     let post_data = chall.ctx.banks_client.get_account(post).await?.unwrap().data;
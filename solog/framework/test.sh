@@ -0,0 +1,7 @@
+# runs the program tests for every feature set the program is built with
+
+cd chall/ || exit 1
+for features in "" dev-tools free-list-heap heap-canary,dev-tools heap-stats; do
+    echo "features: ${features:-default}"
+    cargo test --features "$features" || exit 1
+done
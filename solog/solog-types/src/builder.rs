@@ -0,0 +1,688 @@
+//! Instruction builders for every [`SologInstruction`], deriving the pdas
+//! and ordering the accounts the way the program expects them.
+
+use borsh::to_vec;
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    system_program,
+};
+
+use crate::{
+    instruction::SologInstruction,
+    pda::{
+        find_access_receipt_address, find_block_list_address, find_bounty_address,
+        find_comment_address, find_config_address, find_flag_address, find_follow_address,
+        find_post_address, find_profile_address, find_program_data_address,
+        find_proposal_address, find_rate_limit_address, find_reaction_address,
+        find_reactions_address,
+    },
+    state::ReactionKind,
+};
+
+pub fn create_post(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    title: Vec<u8>,
+    content: Vec<u8>,
+) -> Instruction {
+    let (post, _) = find_post_address(program_id, author, &title);
+
+    build(
+        program_id,
+        SologInstruction::CreatePost { title, content },
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new(post, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn add_collaborator(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    collaborator: &Pubkey,
+    post: &Pubkey,
+) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::AddCollaborator,
+        vec![
+            AccountMeta::new_readonly(*author, true),
+            AccountMeta::new_readonly(*collaborator, false),
+            AccountMeta::new(*post, false),
+        ],
+    )
+}
+
+pub fn set_moderator(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    collaborator: &Pubkey,
+    post: &Pubkey,
+    moderator: bool,
+) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::SetModerator { moderator },
+        vec![
+            AccountMeta::new_readonly(*author, true),
+            AccountMeta::new_readonly(*collaborator, false),
+            AccountMeta::new(*post, false),
+        ],
+    )
+}
+
+pub fn clap(
+    program_id: &Pubkey,
+    viewer: &Pubkey,
+    post_or_comment: &Pubkey,
+    data: Vec<u8>,
+) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::Clap { data },
+        vec![
+            AccountMeta::new_readonly(*viewer, true),
+            AccountMeta::new(*post_or_comment, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// `order` is the current `comment_count` of the post
+pub fn add_comment(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    post: &Pubkey,
+    order: u8,
+    content: Vec<u8>,
+) -> Instruction {
+    let (comment, _) = find_comment_address(program_id, post, author, order);
+    let (block_list, _) = find_block_list_address(program_id, post);
+    let (config, _) = find_config_address(program_id);
+    let (rate_limit, _) = find_rate_limit_address(program_id, post, author);
+
+    build(
+        program_id,
+        SologInstruction::AddComment { content },
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new(*post, false),
+            AccountMeta::new(comment, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(block_list, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new(rate_limit, false),
+        ],
+    )
+}
+
+/// `order` is the `order` of the comment being edited
+pub fn edit_comment(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    post: &Pubkey,
+    order: u8,
+    content: Vec<u8>,
+) -> Instruction {
+    let (comment, _) = find_comment_address(program_id, post, author, order);
+    let (block_list, _) = find_block_list_address(program_id, post);
+
+    build(
+        program_id,
+        SologInstruction::EditComment { content },
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new(comment, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(*post, false),
+            AccountMeta::new_readonly(block_list, false),
+        ],
+    )
+}
+
+pub fn pin_comment(
+    program_id: &Pubkey,
+    moderator: &Pubkey,
+    post: &Pubkey,
+    comment: &Pubkey,
+) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::PinComment,
+        vec![
+            AccountMeta::new_readonly(*moderator, true),
+            AccountMeta::new(*post, false),
+            AccountMeta::new_readonly(*comment, false),
+        ],
+    )
+}
+
+pub fn unpin_comment(
+    program_id: &Pubkey,
+    moderator: &Pubkey,
+    post: &Pubkey,
+    comment: &Pubkey,
+) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::UnpinComment,
+        vec![
+            AccountMeta::new_readonly(*moderator, true),
+            AccountMeta::new(*post, false),
+            AccountMeta::new_readonly(*comment, false),
+        ],
+    )
+}
+
+pub fn lock_comments(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    post: &Pubkey,
+    locked: bool,
+) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::LockComments { locked },
+        vec![
+            AccountMeta::new_readonly(*author, true),
+            AccountMeta::new(*post, false),
+        ],
+    )
+}
+
+pub fn block_commenter(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    post: &Pubkey,
+    commenter: &Pubkey,
+) -> Instruction {
+    let (block_list, _) = find_block_list_address(program_id, post);
+
+    build(
+        program_id,
+        SologInstruction::BlockCommenter,
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new_readonly(*post, false),
+            AccountMeta::new_readonly(*commenter, false),
+            AccountMeta::new(block_list, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn unblock_commenter(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    post: &Pubkey,
+    commenter: &Pubkey,
+) -> Instruction {
+    let (block_list, _) = find_block_list_address(program_id, post);
+
+    build(
+        program_id,
+        SologInstruction::UnblockCommenter,
+        vec![
+            AccountMeta::new_readonly(*author, true),
+            AccountMeta::new_readonly(*post, false),
+            AccountMeta::new_readonly(*commenter, false),
+            AccountMeta::new(block_list, false),
+        ],
+    )
+}
+
+pub fn init_config(
+    program_id: &Pubkey,
+    admin: &Pubkey,
+    comment_slot_window: u64,
+    flag_threshold: u32,
+) -> Instruction {
+    let (config, _) = find_config_address(program_id);
+    let (program_data, _) = find_program_data_address(program_id);
+
+    build(
+        program_id,
+        SologInstruction::InitConfig {
+            comment_slot_window,
+            flag_threshold,
+        },
+        vec![
+            AccountMeta::new(*admin, true),
+            AccountMeta::new(config, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new_readonly(program_data, false),
+        ],
+    )
+}
+
+pub fn update_config(
+    program_id: &Pubkey,
+    admin: &Pubkey,
+    comment_slot_window: u64,
+    flag_threshold: u32,
+) -> Instruction {
+    let (config, _) = find_config_address(program_id);
+
+    build(
+        program_id,
+        SologInstruction::UpdateConfig {
+            comment_slot_window,
+            flag_threshold,
+        },
+        vec![
+            AccountMeta::new_readonly(*admin, true),
+            AccountMeta::new(config, false),
+        ],
+    )
+}
+
+pub fn set_post_price(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    post: &Pubkey,
+    price: u64,
+    gated_content: Vec<u8>,
+) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::SetPostPrice { price, gated_content },
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new(*post, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// `collaborators` must be the post collaborators in post order
+pub fn purchase_access(
+    program_id: &Pubkey,
+    reader: &Pubkey,
+    post: &Pubkey,
+    author: &Pubkey,
+    collaborators: &[Pubkey],
+) -> Instruction {
+    let (access_receipt, _) = find_access_receipt_address(program_id, post, reader);
+
+    let mut accounts = vec![
+        AccountMeta::new(*reader, true),
+        AccountMeta::new_readonly(*post, false),
+        AccountMeta::new(access_receipt, false),
+        AccountMeta::new_readonly(system_program::id(), false),
+        AccountMeta::new(*author, false),
+    ];
+    accounts.extend(
+        collaborators
+            .iter()
+            .map(|collaborator| AccountMeta::new(*collaborator, false)),
+    );
+
+    build(program_id, SologInstruction::PurchaseAccess, accounts)
+}
+
+pub fn follow(program_id: &Pubkey, follower: &Pubkey, following: &Pubkey) -> Instruction {
+    let (follower_profile, _) = find_profile_address(program_id, follower);
+    let (following_profile, _) = find_profile_address(program_id, following);
+    let (follow, _) = find_follow_address(program_id, follower, following);
+
+    build(
+        program_id,
+        SologInstruction::Follow,
+        vec![
+            AccountMeta::new(*follower, true),
+            AccountMeta::new_readonly(*following, false),
+            AccountMeta::new(follower_profile, false),
+            AccountMeta::new(following_profile, false),
+            AccountMeta::new(follow, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn unfollow(program_id: &Pubkey, follower: &Pubkey, following: &Pubkey) -> Instruction {
+    let (follower_profile, _) = find_profile_address(program_id, follower);
+    let (following_profile, _) = find_profile_address(program_id, following);
+    let (follow, _) = find_follow_address(program_id, follower, following);
+
+    build(
+        program_id,
+        SologInstruction::Unfollow,
+        vec![
+            AccountMeta::new(*follower, true),
+            AccountMeta::new_readonly(*following, false),
+            AccountMeta::new(follower_profile, false),
+            AccountMeta::new(following_profile, false),
+            AccountMeta::new(follow, false),
+        ],
+    )
+}
+
+pub fn set_approval_threshold(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    post: &Pubkey,
+    threshold: u8,
+    require_author: bool,
+) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::SetApprovalThreshold {
+            threshold,
+            require_author,
+        },
+        vec![
+            AccountMeta::new_readonly(*author, true),
+            AccountMeta::new(*post, false),
+        ],
+    )
+}
+
+pub fn propose_edit(
+    program_id: &Pubkey,
+    proposer: &Pubkey,
+    post: &Pubkey,
+    content: Vec<u8>,
+) -> Instruction {
+    let (proposal, _) = find_proposal_address(program_id, post, proposer);
+
+    build(
+        program_id,
+        SologInstruction::ProposeEdit { content },
+        vec![
+            AccountMeta::new(*proposer, true),
+            AccountMeta::new_readonly(*post, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn approve_proposal(
+    program_id: &Pubkey,
+    approver: &Pubkey,
+    post: &Pubkey,
+    proposer: &Pubkey,
+) -> Instruction {
+    let (proposal, _) = find_proposal_address(program_id, post, proposer);
+
+    build(
+        program_id,
+        SologInstruction::ApproveProposal,
+        vec![
+            AccountMeta::new_readonly(*approver, true),
+            AccountMeta::new_readonly(*post, false),
+            AccountMeta::new(proposal, false),
+        ],
+    )
+}
+
+pub fn execute_proposal(
+    program_id: &Pubkey,
+    executor: &Pubkey,
+    post: &Pubkey,
+    proposer: &Pubkey,
+) -> Instruction {
+    let (proposal, _) = find_proposal_address(program_id, post, proposer);
+
+    build(
+        program_id,
+        SologInstruction::ExecuteProposal,
+        vec![
+            AccountMeta::new(*executor, true),
+            AccountMeta::new(*post, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new(*proposer, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn cancel_proposal(
+    program_id: &Pubkey,
+    canceller: &Pubkey,
+    post: &Pubkey,
+    proposer: &Pubkey,
+) -> Instruction {
+    let (proposal, _) = find_proposal_address(program_id, post, proposer);
+
+    build(
+        program_id,
+        SologInstruction::CancelProposal,
+        vec![
+            AccountMeta::new_readonly(*canceller, true),
+            AccountMeta::new_readonly(*post, false),
+            AccountMeta::new(proposal, false),
+            AccountMeta::new(*proposer, false),
+        ],
+    )
+}
+
+pub fn get_post_summary(program_id: &Pubkey, post: &Pubkey) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::GetPostSummary,
+        vec![AccountMeta::new_readonly(*post, false)],
+    )
+}
+
+pub fn get_comment(program_id: &Pubkey, post: &Pubkey, comment: &Pubkey) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::GetComment,
+        vec![
+            AccountMeta::new_readonly(*post, false),
+            AccountMeta::new_readonly(*comment, false),
+        ],
+    )
+}
+
+pub fn react(
+    program_id: &Pubkey,
+    viewer: &Pubkey,
+    target: &Pubkey,
+    kind: ReactionKind,
+) -> Instruction {
+    let (reactions, _) = find_reactions_address(program_id, target);
+    let (reaction, _) = find_reaction_address(program_id, target, viewer);
+
+    build(
+        program_id,
+        SologInstruction::React { kind },
+        vec![
+            AccountMeta::new(*viewer, true),
+            AccountMeta::new_readonly(*target, false),
+            AccountMeta::new(reactions, false),
+            AccountMeta::new(reaction, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn create_bounty(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    post: &Pubkey,
+    amount: u64,
+    expires_at: i64,
+) -> Instruction {
+    let (bounty, _) = find_bounty_address(program_id, post);
+
+    build(
+        program_id,
+        SologInstruction::CreateBounty { amount, expires_at },
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new_readonly(*post, false),
+            AccountMeta::new(bounty, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+pub fn award_bounty(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    post: &Pubkey,
+    comment: &Pubkey,
+    comment_author: &Pubkey,
+) -> Instruction {
+    let (bounty, _) = find_bounty_address(program_id, post);
+
+    build(
+        program_id,
+        SologInstruction::AwardBounty,
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new_readonly(*post, false),
+            AccountMeta::new_readonly(*comment, false),
+            AccountMeta::new(*comment_author, false),
+            AccountMeta::new(bounty, false),
+        ],
+    )
+}
+
+pub fn reclaim_bounty(program_id: &Pubkey, author: &Pubkey, post: &Pubkey) -> Instruction {
+    let (bounty, _) = find_bounty_address(program_id, post);
+
+    build(
+        program_id,
+        SologInstruction::ReclaimBounty,
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new_readonly(*post, false),
+            AccountMeta::new(bounty, false),
+        ],
+    )
+}
+
+pub fn repost(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    original: &Pubkey,
+    title: Vec<u8>,
+    content: Vec<u8>,
+) -> Instruction {
+    let (post, _) = find_post_address(program_id, author, &title);
+
+    build(
+        program_id,
+        SologInstruction::Repost { title, content },
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new(post, false),
+            AccountMeta::new(*original, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// Flag a post or comment, `target` being its pda
+pub fn flag(program_id: &Pubkey, flagger: &Pubkey, target: &Pubkey) -> Instruction {
+    let (flag, _) = find_flag_address(program_id, target, flagger);
+    let (config, _) = find_config_address(program_id);
+
+    build(
+        program_id,
+        SologInstruction::Flag,
+        vec![
+            AccountMeta::new(*flagger, true),
+            AccountMeta::new(*target, false),
+            AccountMeta::new(flag, false),
+            AccountMeta::new_readonly(config, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// Unhide `target`, a comment of `post` or `post` itself, closing the flags
+/// of `flaggers`
+pub fn unhide(
+    program_id: &Pubkey,
+    reviewer: &Pubkey,
+    post: &Pubkey,
+    target: &Pubkey,
+    flaggers: &[Pubkey],
+) -> Instruction {
+    let (config, _) = find_config_address(program_id);
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*reviewer, true),
+        AccountMeta::new(*target, false),
+        AccountMeta::new_readonly(config, false),
+        AccountMeta::new_readonly(*post, false),
+    ];
+    accounts.extend(flaggers.iter().flat_map(|flagger| {
+        [
+            AccountMeta::new(find_flag_address(program_id, target, flagger).0, false),
+            AccountMeta::new(*flagger, false),
+        ]
+    }));
+
+    build(program_id, SologInstruction::Unhide, accounts)
+}
+
+pub fn set_post_expiry(
+    program_id: &Pubkey,
+    author: &Pubkey,
+    post: &Pubkey,
+    expires_at: Option<i64>,
+) -> Instruction {
+    build(
+        program_id,
+        SologInstruction::SetPostExpiry { expires_at },
+        vec![
+            AccountMeta::new(*author, true),
+            AccountMeta::new(*post, false),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+    )
+}
+
+/// `comments` are pairs of comment pda and the `rent_payer` of the comment
+pub fn close_expired(
+    program_id: &Pubkey,
+    post: &Pubkey,
+    author: &Pubkey,
+    rent_payer: &Pubkey,
+    comments: &[(Pubkey, Pubkey)],
+) -> Instruction {
+    let (bounty, _) = find_bounty_address(program_id, post);
+
+    let mut accounts = vec![
+        AccountMeta::new(*post, false),
+        AccountMeta::new(*author, false),
+        AccountMeta::new(bounty, false),
+        AccountMeta::new(*rent_payer, false),
+    ];
+    accounts.extend(comments.iter().flat_map(|(comment, comment_rent_payer)| {
+        [
+            AccountMeta::new(*comment, false),
+            AccountMeta::new(*comment_rent_payer, false),
+        ]
+    }));
+
+    build(program_id, SologInstruction::CloseExpired, accounts)
+}
+
+/// Append `payer` to an instruction taking an optional payer, funding the
+/// accounts it creates instead of the signer
+pub fn with_payer(mut instruction: Instruction, payer: &Pubkey) -> Instruction {
+    instruction.accounts.push(AccountMeta::new(*payer, true));
+    instruction
+}
+
+fn build(
+    program_id: &Pubkey,
+    instruction: SologInstruction,
+    accounts: Vec<AccountMeta>,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts,
+        // serializing into a vec can not fail
+        data: to_vec(&instruction).unwrap(),
+    }
+}
@@ -0,0 +1,76 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{to_vec, BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+use crate::{
+    instruction::HeapChunk,
+    state::{ReactionKind, REACTION_KINDS},
+};
+
+/// Prefix the runtime puts in front of `sol_log_data` output in transaction logs
+pub const LOG_DATA_PREFIX: &str = "Program data: ";
+
+/// Structured program activity, emitted once per successful instruction
+#[derive(Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum SologEvent {
+    PostCreated { post: Pubkey, author: Pubkey },
+    CollaboratorAdded { post: Pubkey, collaborator: Pubkey },
+    Clapped { target: Pubkey, viewer: Pubkey, claps: u8 },
+    CommentAdded { post: Pubkey, comment: Pubkey, author: Pubkey, order: u8 },
+    CommentEdited { comment: Pubkey, author: Pubkey },
+    CommentPinned { post: Pubkey, comment: Pubkey },
+    CommentUnpinned { post: Pubkey, comment: Pubkey },
+    CommentsLocked { post: Pubkey, locked: bool },
+    CommenterBlocked { post: Pubkey, commenter: Pubkey },
+    CommenterUnblocked { post: Pubkey, commenter: Pubkey },
+    ConfigUpdated { admin: Pubkey, comment_slot_window: u64, flag_threshold: u32 },
+    PostPriceSet { post: Pubkey, price: u64 },
+    AccessPurchased { post: Pubkey, reader: Pubkey, price: u64 },
+    Followed { follower: Pubkey, following: Pubkey },
+    Unfollowed { follower: Pubkey, following: Pubkey },
+    ApprovalThresholdSet { post: Pubkey, threshold: u8, require_author: bool },
+    ProposalCreated { post: Pubkey, proposal: Pubkey, proposer: Pubkey },
+    ProposalApproved { proposal: Pubkey, approver: Pubkey, approvals: u8 },
+    ProposalExecuted { post: Pubkey, proposal: Pubkey },
+    /// Heap chunks walked by the dev tools
+    HeapDumped { chunks: Vec<HeapChunk> },
+    Reacted {
+        target: Pubkey,
+        viewer: Pubkey,
+        kind: ReactionKind,
+        totals: [u32; REACTION_KINDS],
+    },
+    BountyCreated { post: Pubkey, amount: u64, expires_at: i64 },
+    BountyAwarded { post: Pubkey, comment: Pubkey, winner: Pubkey, amount: u64 },
+    BountyReclaimed { post: Pubkey, amount: u64 },
+    Reposted { post: Pubkey, original: Pubkey, author: Pubkey, reposts: u32 },
+    Flagged { target: Pubkey, flagger: Pubkey, flags: u32, hidden: bool },
+    Unhidden { target: Pubkey, reviewer: Pubkey, flags: u32 },
+    PostExpirySet { post: Pubkey, expires_at: Option<i64> },
+    ExpiredClosed { post: Pubkey, comments_closed: u8, remaining_comments: u8 },
+    ModeratorSet { post: Pubkey, collaborator: Pubkey, moderator: bool },
+    ProposalCancelled { post: Pubkey, proposal: Pubkey },
+}
+
+impl SologEvent {
+    pub fn emit(&self) -> ProgramResult {
+        sol_log_data(&[&to_vec(self)?]);
+        Ok(())
+    }
+
+    /// Decode an event from a single transaction log line,
+    /// `None` for lines that are not solog events
+    pub fn from_log(log: &str) -> Option<Self> {
+        let data = log.strip_prefix(LOG_DATA_PREFIX)?;
+        // sol_log_data joins fields with spaces, events are a single field
+        let data = STANDARD.decode(data.split(' ').next()?).ok()?;
+        Self::try_from_slice(&data).ok()
+    }
+
+    /// Decode every event found in the logs of a transaction
+    pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Vec<Self> {
+        logs.iter()
+            .filter_map(|log| Self::from_log(log.as_ref()))
+            .collect()
+    }
+}
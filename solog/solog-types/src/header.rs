@@ -0,0 +1,289 @@
+//! Zero-copy views of the fixed size head of serialized posts and comments.
+//!
+//! The scalar fields of `Post` and `Comment` come before their variable
+//! length tails, so they sit at fixed offsets after the `SologData` tag and
+//! can be read and updated in place without deserializing the whole account.
+
+use std::mem::{align_of, size_of};
+
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use crate::state::{MAX_COLLABORATORS, MAX_PINNED_COMMENTS};
+
+/// Borsh tag of `SologData::Post`
+pub const POST_TAG: u8 = 0;
+/// Borsh tag of `SologData::Comment`
+pub const COMMENT_TAG: u8 = 1;
+
+/// Head of a serialized `SologData::Post`, up to and including the author
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct PostHeader {
+    pub claps: u8,
+    pub comment_count: u8,
+
+    pub collaborators: [Pubkey; MAX_COLLABORATORS],
+    pub collaborator_count: u8,
+    pub moderators: u8,
+
+    pub pinned_comments: [Pubkey; MAX_PINNED_COMMENTS],
+    pub pinned_count: u8,
+
+    comments_locked: u8,
+
+    pub approval_threshold: u8,
+    require_author: u8,
+
+    price: [u8; 8],
+
+    reposts: [u8; 4],
+
+    flags: [u8; 4],
+    hidden: u8,
+
+    pub author: Pubkey,
+}
+
+impl PostHeader {
+    /// View the header of post account data, `None` for other accounts
+    pub fn from_account_data(data: &[u8]) -> Option<&Self> {
+        view(data, POST_TAG)
+    }
+
+    pub fn from_account_data_mut(data: &mut [u8]) -> Option<&mut Self> {
+        view_mut(data, POST_TAG)
+    }
+
+    pub fn comments_locked(&self) -> bool {
+        self.comments_locked != 0
+    }
+
+    pub fn set_comments_locked(&mut self, locked: bool) {
+        self.comments_locked = locked as u8;
+    }
+
+    pub fn require_author(&self) -> bool {
+        self.require_author != 0
+    }
+
+    pub fn price(&self) -> u64 {
+        u64::from_le_bytes(self.price)
+    }
+
+    pub fn set_price(&mut self, price: u64) {
+        self.price = price.to_le_bytes();
+    }
+
+    pub fn reposts(&self) -> u32 {
+        u32::from_le_bytes(self.reposts)
+    }
+
+    pub fn set_reposts(&mut self, reposts: u32) {
+        self.reposts = reposts.to_le_bytes();
+    }
+
+    pub fn flags(&self) -> u32 {
+        u32::from_le_bytes(self.flags)
+    }
+
+    pub fn set_flags(&mut self, flags: u32) {
+        self.flags = flags.to_le_bytes();
+    }
+
+    pub fn hidden(&self) -> bool {
+        self.hidden != 0
+    }
+
+    pub fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden as u8;
+    }
+}
+
+/// Head of a serialized `SologData::Comment`, up to and including the author
+#[derive(Clone, Copy, Debug)]
+#[repr(C)]
+pub struct CommentHeader {
+    pub claps: u8,
+    pub order: u8,
+    flags: [u8; 4],
+    hidden: u8,
+    pub author: Pubkey,
+}
+
+impl CommentHeader {
+    /// View the header of comment account data, `None` for other accounts
+    pub fn from_account_data(data: &[u8]) -> Option<&Self> {
+        view(data, COMMENT_TAG)
+    }
+
+    pub fn from_account_data_mut(data: &mut [u8]) -> Option<&mut Self> {
+        view_mut(data, COMMENT_TAG)
+    }
+
+    pub fn flags(&self) -> u32 {
+        u32::from_le_bytes(self.flags)
+    }
+
+    pub fn set_flags(&mut self, flags: u32) {
+        self.flags = flags.to_le_bytes();
+    }
+
+    pub fn hidden(&self) -> bool {
+        self.hidden != 0
+    }
+
+    pub fn set_hidden(&mut self, hidden: bool) {
+        self.hidden = hidden as u8;
+    }
+}
+
+// The headers only hold bytes and byte arrays, so they have no padding and
+// any bytes make a valid header. The derives would check the same but leave
+// unused items behind, the layout is asserted here instead
+const _: () = assert!(align_of::<PostHeader>() == 1 && align_of::<CommentHeader>() == 1);
+const _: () = assert!(
+    size_of::<PostHeader>()
+        == 2 + 32 * MAX_COLLABORATORS + 2 + 32 * MAX_PINNED_COMMENTS + 4 + 8 + 4 + 4 + 1 + 32
+);
+const _: () = assert!(size_of::<CommentHeader>() == 2 + 4 + 1 + 32);
+
+unsafe impl Zeroable for PostHeader {}
+unsafe impl Pod for PostHeader {}
+
+unsafe impl Zeroable for CommentHeader {}
+unsafe impl Pod for CommentHeader {}
+
+fn view<T: Pod>(data: &[u8], tag: u8) -> Option<&T> {
+    match data.split_first() {
+        Some((&found, rest)) if found == tag => {
+            rest.get(..size_of::<T>()).map(bytemuck::from_bytes)
+        }
+        _ => None,
+    }
+}
+
+fn view_mut<T: Pod>(data: &mut [u8], tag: u8) -> Option<&mut T> {
+    match data.split_first_mut() {
+        Some((&mut found, rest)) if found == tag => {
+            rest.get_mut(..size_of::<T>()).map(bytemuck::from_bytes_mut)
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use borsh::{to_vec, BorshDeserialize};
+
+    use super::*;
+    use crate::state::{Comment, Post, SologData};
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    fn post() -> Post {
+        Post {
+            claps: 7,
+            comment_count: 12,
+            collaborators: [key(1), key(2), key(3)],
+            collaborator_count: 2,
+            moderators: 0b10,
+            pinned_comments: [key(4), key(5), key(6)],
+            pinned_count: 3,
+            comments_locked: true,
+            approval_threshold: 2,
+            require_author: true,
+            price: 0x0102_0304_0506_0708,
+            reposts: 0x0a0b_0c0d,
+            flags: 0x1112_1314,
+            hidden: true,
+            ..Post::new(key(9), b"title".to_vec(), b"content".to_vec())
+        }
+    }
+
+    #[test]
+    fn post_header_reads_every_scalar() {
+        let data = to_vec(&SologData::Post(post())).unwrap();
+        let header = PostHeader::from_account_data(&data).unwrap();
+        let post = post();
+
+        assert_eq!(header.claps, post.claps);
+        assert_eq!(header.comment_count, post.comment_count);
+        assert_eq!(header.collaborators, post.collaborators);
+        assert_eq!(header.collaborator_count, post.collaborator_count);
+        assert_eq!(header.moderators, post.moderators);
+        assert_eq!(header.pinned_comments, post.pinned_comments);
+        assert_eq!(header.pinned_count, post.pinned_count);
+        assert_eq!(header.comments_locked(), post.comments_locked);
+        assert_eq!(header.approval_threshold, post.approval_threshold);
+        assert_eq!(header.require_author(), post.require_author);
+        assert_eq!(header.price(), post.price);
+        assert_eq!(header.reposts(), post.reposts);
+        assert_eq!(header.flags(), post.flags);
+        assert_eq!(header.hidden(), post.hidden);
+        assert_eq!(header.author, post.author);
+
+        assert!(CommentHeader::from_account_data(&data).is_none());
+    }
+
+    #[test]
+    fn post_header_writes_in_place() {
+        let mut data = to_vec(&SologData::Post(post())).unwrap();
+        let header = PostHeader::from_account_data_mut(&mut data).unwrap();
+        header.set_price(42);
+        header.set_reposts(3);
+        header.set_flags(0);
+        header.set_hidden(false);
+        header.set_comments_locked(false);
+
+        match SologData::try_from_slice(&data).unwrap() {
+            SologData::Post(post) => {
+                assert_eq!(post.price, 42);
+                assert_eq!(post.reposts, 3);
+                assert_eq!(post.flags, 0);
+                assert!(!post.hidden);
+                assert!(!post.comments_locked);
+                assert_eq!(post.author, key(9));
+                assert_eq!(post.content, b"content");
+            }
+            data => panic!("not a post: {data:?}"),
+        }
+    }
+
+    #[test]
+    fn comment_header_reads_every_scalar() {
+        let comment = || Comment {
+            claps: 3,
+            order: 5,
+            flags: 0x0102_0304,
+            hidden: true,
+            author: key(8),
+            content: b"comment".to_vec(),
+            rent_payer: key(9),
+        };
+        let mut data = to_vec(&SologData::Comment(comment())).unwrap();
+        let header = CommentHeader::from_account_data(&data).unwrap();
+        let comment = comment();
+
+        assert_eq!(header.claps, comment.claps);
+        assert_eq!(header.order, comment.order);
+        assert_eq!(header.flags(), comment.flags);
+        assert_eq!(header.hidden(), comment.hidden);
+        assert_eq!(header.author, comment.author);
+        assert!(PostHeader::from_account_data(&data).is_none());
+
+        let header = CommentHeader::from_account_data_mut(&mut data).unwrap();
+        header.set_flags(1);
+        header.set_hidden(false);
+        match SologData::try_from_slice(&data).unwrap() {
+            SologData::Comment(comment) => {
+                assert_eq!(comment.flags, 1);
+                assert!(!comment.hidden);
+                assert_eq!(comment.content, b"comment");
+            }
+            data => panic!("not a comment: {data:?}"),
+        }
+    }
+}
@@ -0,0 +1,362 @@
+use std::fmt;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+
+use crate::state::ReactionKind;
+
+pub const MAX_HEAP_DEV_COUNT: usize = 6;
+pub const MAX_HEAP_DUMP_COUNT: usize = 32;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub enum HeapCommand {
+    Allocate {
+        size: u64,
+    },
+    Edit {
+        index: u64,
+        data: Vec<u8>,
+        resize: bool,
+    },
+    Search {
+        index: u64,
+    },
+    /// Walk `count` chunks starting at index `from` (1 based like `Search`)
+    /// and emit them as a `SologEvent::HeapDumped`
+    Dump {
+        from: u64,
+        count: u64,
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct HeapKit {
+    pub commands: Vec<HeapCommand>
+}
+
+/// One heap chunk as reported by `HeapCommand::Dump`
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct HeapChunk {
+    pub index: u64,
+    /// address of the chunk data
+    pub address: u64,
+    pub length: u64,
+    /// address of the next chunk length, as stored after the data
+    pub next: u64,
+}
+
+impl fmt::Display for HeapChunk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:>5}  {:#012x}  {:>6}  {:#012x}",
+            self.index, self.address, self.length, self.next
+        )
+    }
+}
+
+/// Render a heap dump as a table, one chunk per line
+pub fn format_heap_dump(chunks: &[HeapChunk]) -> String {
+    let mut table = format!("{:>5}  {:<12}  {:>6}  {:<12}\n", "index", "address", "length", "next");
+    for chunk in chunks {
+        table.push_str(&format!("{}\n", chunk));
+    }
+    table
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub enum SologInstruction {
+    /// Create a new post
+    /// accounts:
+    ///  0. author
+    ///  1. post pda
+    ///  2. system_program
+    ///  3. optional payer, defaults to author
+    CreatePost { title: Vec<u8>, content: Vec<u8> },
+
+    /// Add a collaborator to a post
+    /// accounts:
+    ///  0. author
+    ///  1. collaborator
+    ///  2. post pda
+    AddCollaborator,
+
+    /// Clap for a post
+    /// accounts:
+    ///  0. viewer
+    ///  1. post or comment pda
+    ///  2. system_program
+    Clap { data: Vec<u8> },
+
+    /// Comment on a post
+    /// accounts:
+    ///  0. author
+    ///  1. post pda
+    ///  2. comment pda
+    ///  3. system_program
+    ///  4. block list pda
+    ///  5. config pda
+    ///  6. rate limit pda
+    ///  7. optional payer, defaults to author
+    AddComment { content: Vec<u8> },
+
+    /// Edit a comment
+    /// accounts:
+    /// 0. author
+    /// 1. comment pda
+    /// 2. system_program
+    /// 3. post pda
+    /// 4. block list pda
+    /// 5. optional payer, defaults to author
+    EditComment { content: Vec<u8> },
+
+    /// Pin a comment on a post
+    /// accounts:
+    /// 0. author or moderator
+    /// 1. post pda
+    /// 2. comment pda
+    PinComment,
+
+    /// Unpin a comment from a post
+    /// accounts:
+    /// 0. author or moderator
+    /// 1. post pda
+    /// 2. comment pda
+    UnpinComment,
+
+    /// Lock or unlock comments on a post
+    /// accounts:
+    /// 0. author
+    /// 1. post pda
+    LockComments { locked: bool },
+
+    /// Block a commenter on a post
+    /// accounts:
+    /// 0. author
+    /// 1. post pda
+    /// 2. commenter
+    /// 3. block list pda
+    /// 4. system_program
+    /// 5. optional payer, defaults to author
+    BlockCommenter,
+
+    /// Unblock a commenter on a post
+    /// accounts:
+    /// 0. author
+    /// 1. post pda
+    /// 2. commenter
+    /// 3. block list pda
+    UnblockCommenter,
+
+    /// Initialize the program config, the signer must be the upgrade
+    /// authority of the program and becomes the admin
+    /// accounts:
+    /// 0. admin
+    /// 1. config pda
+    /// 2. system_program
+    /// 3. program data account of the program
+    InitConfig {
+        comment_slot_window: u64,
+        flag_threshold: u32,
+    },
+
+    /// Update the program config
+    /// accounts:
+    /// 0. admin
+    /// 1. config pda
+    UpdateConfig {
+        comment_slot_window: u64,
+        flag_threshold: u32,
+    },
+
+    /// Set the access price and gated content reference of a post, rent
+    /// freed by a shorter reference is refunded to the author
+    /// accounts:
+    /// 0. author
+    /// 1. post pda
+    /// 2. system_program
+    /// 3. optional payer, defaults to author
+    SetPostPrice { price: u64, gated_content: Vec<u8> },
+
+    /// Pay for access to a post, split between author and collaborators
+    /// accounts:
+    /// 0. reader
+    /// 1. post pda
+    /// 2. access receipt pda
+    /// 3. system_program
+    /// 4. author
+    /// 5. and following: collaborators, in post order
+    PurchaseAccess,
+
+    /// Follow an author
+    /// accounts:
+    /// 0. follower
+    /// 1. following
+    /// 2. follower profile pda
+    /// 3. following profile pda
+    /// 4. follow pda
+    /// 5. system_program
+    /// 6. optional payer, defaults to follower
+    Follow,
+
+    /// Unfollow an author
+    /// accounts:
+    /// 0. follower
+    /// 1. following
+    /// 2. follower profile pda
+    /// 3. following profile pda
+    /// 4. follow pda
+    Unfollow,
+
+    /// Set how many approvals an edit proposal needs, and whether the author
+    /// must be one of them
+    /// accounts:
+    /// 0. author
+    /// 1. post pda
+    SetApprovalThreshold { threshold: u8, require_author: bool },
+
+    /// Propose new content for a collaborative post
+    /// accounts:
+    /// 0. author or collaborator
+    /// 1. post pda
+    /// 2. proposal pda
+    /// 3. system_program
+    /// 4. optional payer, defaults to proposer
+    ProposeEdit { content: Vec<u8> },
+
+    /// Approve a pending edit proposal
+    /// accounts:
+    /// 0. author or collaborator
+    /// 1. post pda
+    /// 2. proposal pda
+    ApproveProposal,
+
+    /// Apply an edit proposal approved by at least the approval threshold of
+    /// the post, and by its author if required, and close it
+    /// accounts:
+    /// 0. author or collaborator
+    /// 1. post pda
+    /// 2. proposal pda
+    /// 3. proposer
+    /// 4. system_program
+    ExecuteProposal,
+
+
+    /// Return a `PostSummary` of a post through the return data
+    /// accounts:
+    /// 0. post pda
+    GetPostSummary,
+
+    /// Return a `CommentSummary` of a comment through the return data
+    /// accounts:
+    /// 0. post pda
+    /// 1. comment pda
+    GetComment,
+
+
+    /// React to a post or comment, at most once per kind and viewer
+    /// accounts:
+    /// 0. viewer
+    /// 1. post or comment pda
+    /// 2. reactions pda
+    /// 3. viewer reaction pda
+    /// 4. system_program
+    /// 5. optional payer, defaults to viewer
+    React { kind: ReactionKind },
+
+
+    /// Escrow lamports in the bounty vault of a post for its best comment
+    /// accounts:
+    /// 0. author
+    /// 1. post pda
+    /// 2. bounty vault pda
+    /// 3. system_program
+    /// 4. optional payer of the vault and its bounty, defaults to author
+    CreateBounty { amount: u64, expires_at: i64 },
+
+    /// Pay the bounty to the author of a comment on the post and close the vault
+    /// accounts:
+    /// 0. author
+    /// 1. post pda
+    /// 2. comment pda
+    /// 3. comment author
+    /// 4. bounty vault pda
+    AwardBounty,
+
+    /// Close an expired bounty vault, refunding the author
+    /// accounts:
+    /// 0. author
+    /// 1. post pda
+    /// 2. bounty vault pda
+    ReclaimBounty,
+
+
+    /// Create a post reposting another one, `content` is optional commentary
+    /// accounts:
+    /// 0. author
+    /// 1. post pda
+    /// 2. original post pda
+    /// 3. system_program
+    /// 4. payer (optional, defaults to the author)
+    Repost { title: Vec<u8>, content: Vec<u8> },
+
+    /// Flag a post or comment for review, hiding it once the flags reach
+    /// the threshold of the config
+    /// accounts:
+    /// 0. flagger
+    /// 1. post or comment pda
+    /// 2. flag pda
+    /// 3. config pda
+    /// 4. system_program
+    /// 5. optional payer, defaults to flagger
+    Flag,
+
+    /// Unhide a post or comment after review, closing the given flags so
+    /// their flaggers can flag it again. Posts are reviewed by their author,
+    /// comments by a moderator of their post, either by the config admin
+    /// accounts:
+    /// 0. reviewer
+    /// 1. post or comment pda
+    /// 2. config pda
+    /// 3. post pda, the target itself when unhiding a post
+    /// 4. and following: pairs of flag pda and flagger, refunded its rent
+    Unhide,
+
+    /// Set or clear the time after which a post expires, which must be in the
+    /// future. Expired posts keep their expiry
+    /// accounts:
+    /// 0. author
+    /// 1. post pda
+    /// 2. system_program
+    /// 3. optional payer, defaults to author
+    SetPostExpiry { expires_at: Option<i64> },
+
+    /// Close the given comments of an expired post, refunding their rent to
+    /// whoever paid it, then the post itself once it has no comments left,
+    /// refunded the same way, along with its bounty vault, refunded to the
+    /// post author. The block list, reactions, flags, rate limits and access
+    /// receipts of the post are left open, and so are its edit proposals,
+    /// which their proposers can still cancel
+    /// accounts:
+    /// 0. post pda
+    /// 1. post author
+    /// 2. bounty vault pda
+    /// 3. rent payer of the post
+    /// 4. and following: pairs of comment pda and rent payer of the comment
+    CloseExpired,
+
+    /// Grant or revoke the moderation rights of a collaborator
+    /// accounts:
+    /// 0. author
+    /// 1. collaborator
+    /// 2. post pda
+    SetModerator { moderator: bool },
+
+    /// Withdraw a pending edit proposal, refunding its rent to the proposer.
+    /// The post author can also discard stale proposals
+    /// accounts:
+    /// 0. proposer or author
+    /// 1. post pda
+    /// 2. proposal pda
+    /// 3. proposer
+    CancelProposal,
+}
@@ -0,0 +1,15 @@
+//! Account, instruction and event types of the solog program, shared by the
+//! program itself and host side clients.
+
+use solana_program::pubkey;
+use solana_program::pubkey::Pubkey;
+
+pub mod builder;
+pub mod event;
+pub mod header;
+pub mod instruction;
+pub mod pda;
+pub mod state;
+pub mod view;
+
+pub const ID: Pubkey = pubkey!("so1og11111111111111111111111111111111111111");
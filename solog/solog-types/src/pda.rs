@@ -0,0 +1,127 @@
+use solana_program::{bpf_loader_upgradeable, pubkey::Pubkey};
+
+pub const POST_SUFFIX: &[u8] = b"post";
+pub const COMMENT_SUFFIX: &[u8] = b"comment";
+pub const BLOCK_LIST_SUFFIX: &[u8] = b"block_list";
+pub const CONFIG_SUFFIX: &[u8] = b"config";
+pub const RATE_LIMIT_SUFFIX: &[u8] = b"rate_limit";
+pub const ACCESS_SUFFIX: &[u8] = b"access";
+pub const PROFILE_SUFFIX: &[u8] = b"profile";
+pub const FOLLOW_SUFFIX: &[u8] = b"follow";
+pub const PROPOSAL_SUFFIX: &[u8] = b"proposal";
+pub const REACTIONS_SUFFIX: &[u8] = b"reactions";
+pub const REACTION_SUFFIX: &[u8] = b"reaction";
+pub const BOUNTY_SUFFIX: &[u8] = b"bounty";
+pub const FLAG_SUFFIX: &[u8] = b"flag";
+
+pub fn find_post_address(program_id: &Pubkey, author: &Pubkey, title: &[u8]) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[POST_SUFFIX, &author.to_bytes(), title], program_id)
+}
+
+pub fn find_comment_address(
+    program_id: &Pubkey,
+    post: &Pubkey,
+    author: &Pubkey,
+    order: u8,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            COMMENT_SUFFIX,
+            &post.to_bytes()[..16],
+            &author.to_bytes()[..16],
+            &order.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+pub fn find_block_list_address(program_id: &Pubkey, post: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BLOCK_LIST_SUFFIX, &post.to_bytes()], program_id)
+}
+
+pub fn find_config_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[CONFIG_SUFFIX], program_id)
+}
+
+pub fn find_rate_limit_address(
+    program_id: &Pubkey,
+    post: &Pubkey,
+    author: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[RATE_LIMIT_SUFFIX, &post.to_bytes(), &author.to_bytes()],
+        program_id,
+    )
+}
+
+pub fn find_access_receipt_address(
+    program_id: &Pubkey,
+    post: &Pubkey,
+    reader: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[ACCESS_SUFFIX, &post.to_bytes(), &reader.to_bytes()],
+        program_id,
+    )
+}
+
+pub fn find_profile_address(program_id: &Pubkey, owner: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[PROFILE_SUFFIX, &owner.to_bytes()], program_id)
+}
+
+pub fn find_follow_address(
+    program_id: &Pubkey,
+    follower: &Pubkey,
+    following: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FOLLOW_SUFFIX, &follower.to_bytes(), &following.to_bytes()],
+        program_id,
+    )
+}
+
+pub fn find_proposal_address(
+    program_id: &Pubkey,
+    post: &Pubkey,
+    proposer: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[PROPOSAL_SUFFIX, &post.to_bytes(), &proposer.to_bytes()],
+        program_id,
+    )
+}
+
+pub fn find_reactions_address(program_id: &Pubkey, target: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[REACTIONS_SUFFIX, &target.to_bytes()], program_id)
+}
+
+pub fn find_reaction_address(
+    program_id: &Pubkey,
+    target: &Pubkey,
+    viewer: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[REACTION_SUFFIX, &target.to_bytes(), &viewer.to_bytes()],
+        program_id,
+    )
+}
+
+pub fn find_bounty_address(program_id: &Pubkey, post: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[BOUNTY_SUFFIX, &post.to_bytes()], program_id)
+}
+
+pub fn find_flag_address(
+    program_id: &Pubkey,
+    target: &Pubkey,
+    flagger: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[FLAG_SUFFIX, &target.to_bytes(), &flagger.to_bytes()],
+        program_id,
+    )
+}
+
+/// Program data account of an upgradeable program, holding its upgrade authority
+pub fn find_program_data_address(program_id: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[program_id.as_ref()], &bpf_loader_upgradeable::id())
+}
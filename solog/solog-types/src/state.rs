@@ -0,0 +1,257 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+pub const MAX_COLLABORATORS: usize = 3;
+pub const MAX_PINNED_COMMENTS: usize = 3;
+pub const MAX_BLOCKED_COMMENTERS: usize = 16;
+pub const MAX_GATED_CONTENT_LEN: usize = 128;
+pub const REACTION_KINDS: usize = 5;
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Post {
+    pub claps: u8,
+    pub comment_count: u8,
+
+    pub collaborators: [Pubkey; MAX_COLLABORATORS],
+    pub collaborator_count: u8,
+    /// Bitmask of the collaborators, by position, allowed to moderate
+    pub moderators: u8,
+
+    pub pinned_comments: [Pubkey; MAX_PINNED_COMMENTS],
+    pub pinned_count: u8,
+
+    pub comments_locked: bool,
+
+    /// Approvals needed to execute an edit proposal
+    pub approval_threshold: u8,
+    /// Whether an edit proposal also needs the approval of the author,
+    /// whatever the threshold
+    pub require_author: bool,
+
+    /// Lamports a reader pays for access, 0 for free posts
+    pub price: u64,
+
+    /// Number of reposts referencing this post
+    pub reposts: u32,
+
+    /// Community flags since the post was last reviewed
+    pub flags: u32,
+    /// Set once `flags` reaches the configured threshold
+    pub hidden: bool,
+
+    pub author: Pubkey,
+    pub title: Vec<u8>,
+    pub content: Vec<u8>,
+    /// Reference to the encrypted or off-chain paywalled content
+    pub gated_content: Vec<u8>,
+    /// Post this one reposts, with `content` as optional commentary
+    pub repost_of: Option<Pubkey>,
+    /// Unix timestamp after which anyone can close the post and its comments.
+    /// Once expired the post takes no new comments
+    pub expires_at: Option<i64>,
+    /// Comments of the expired post closed so far, `comment_count` keeps
+    /// counting every comment ever made
+    pub closed_comments: u8,
+    /// Account that paid the rent of the post, refunded when it is closed
+    pub rent_payer: Pubkey,
+}
+
+impl Post {
+    pub fn new(author: Pubkey, title: Vec<u8>, content: Vec<u8>) -> Self {
+        Self {
+            claps: 0,
+            author,
+            collaborator_count: 0,
+            moderators: 0,
+            comment_count: 0,
+            collaborators: [Pubkey::new_from_array([0xff; 32]); MAX_COLLABORATORS],
+            pinned_comments: [Pubkey::default(); MAX_PINNED_COMMENTS],
+            pinned_count: 0,
+            comments_locked: false,
+            approval_threshold: 1,
+            require_author: true,
+            price: 0,
+            reposts: 0,
+            flags: 0,
+            hidden: false,
+            title,
+            content,
+            gated_content: Vec::new(),
+            repost_of: None,
+            expires_at: None,
+            closed_comments: 0,
+            rent_payer: author,
+        }
+    }
+
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    /// The author and the collaborators given moderation rights may
+    /// moderate the post
+    pub fn is_moderator(&self, key: &Pubkey) -> bool {
+        self.author == *key
+            || self.collaborators[..self.collaborator_count as usize]
+                .iter()
+                .enumerate()
+                .any(|(index, collaborator)| {
+                    collaborator == key && self.moderators & (1 << index) != 0
+                })
+    }
+
+    /// Position of `key` in the approval bitmask of a proposal,
+    /// the author is 0 and collaborators follow in post order
+    pub fn approver_index(&self, key: &Pubkey) -> Option<usize> {
+        if self.author == *key {
+            return Some(0);
+        }
+        self.collaborators[..self.collaborator_count as usize]
+            .iter()
+            .position(|collaborator| collaborator == key)
+            .map(|index| index + 1)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Comment {
+    pub claps: u8,
+    pub order: u8,
+    /// See [`Post::flags`] and [`Post::hidden`]
+    pub flags: u32,
+    pub hidden: bool,
+    pub author: Pubkey,
+    pub content: Vec<u8>,
+    /// See [`Post::rent_payer`]
+    pub rent_payer: Pubkey,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct BlockList {
+    pub post: Pubkey,
+    pub blocked: [Pubkey; MAX_BLOCKED_COMMENTERS],
+    pub blocked_count: u8,
+}
+
+impl BlockList {
+    pub fn is_blocked(&self, key: &Pubkey) -> bool {
+        self.blocked[..self.blocked_count as usize].contains(key)
+    }
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct AccessReceipt {
+    pub post: Pubkey,
+    pub reader: Pubkey,
+    pub price: u64,
+}
+
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct Profile {
+    pub owner: Pubkey,
+    pub follower_count: u32,
+    pub following_count: u32,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Follow {
+    pub follower: Pubkey,
+    pub following: Pubkey,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Config {
+    pub admin: Pubkey,
+    /// Minimum number of slots between two comments of the same author on a post
+    pub comment_slot_window: u64,
+    /// Flags that hide a post or comment until it is reviewed, 0 never hides
+    pub flag_threshold: u32,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct RateLimit {
+    pub last_comment_slot: u64,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Proposal {
+    pub post: Pubkey,
+    pub proposer: Pubkey,
+    /// Bitmask of approvers, see [`Post::approver_index`]
+    pub approvals: u8,
+    pub content: Vec<u8>,
+}
+
+impl Proposal {
+    pub fn approval_count(&self) -> u8 {
+        self.approvals.count_ones() as u8
+    }
+}
+
+/// Lamports escrowed by the author of a post for the best comment, held by
+/// the bounty vault pda on top of its rent
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Bounty {
+    pub post: Pubkey,
+    pub amount: u64,
+    /// Unix timestamp after which the author can reclaim the bounty
+    pub expires_at: i64,
+}
+
+/// Reactions a viewer can leave on a post or comment besides claps
+#[derive(Debug, Clone, Copy, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum ReactionKind {
+    Like,
+    Love,
+    Laugh,
+    Wow,
+    Sad,
+}
+
+impl ReactionKind {
+    /// Position of the kind in the reaction counts and viewer bitmask
+    pub fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Reaction totals of a post or comment
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Reactions {
+    pub target: Pubkey,
+    /// Indexed by [`ReactionKind::index`]
+    pub counts: [u32; REACTION_KINDS],
+}
+
+/// Reactions one viewer left on a post or comment
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Reaction {
+    pub target: Pubkey,
+    pub viewer: Pubkey,
+    /// Bitmask of [`ReactionKind::index`]
+    pub kinds: u8,
+}
+
+/// Marks that a signer flagged a post or comment, so each flags it once
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub struct Flag {
+    pub target: Pubkey,
+    pub flagger: Pubkey,
+}
+
+#[derive(Debug, BorshSerialize, BorshDeserialize)]
+pub enum SologData {
+    Post(Post),
+    Comment(Comment),
+    BlockList(BlockList),
+    Config(Config),
+    RateLimit(RateLimit),
+    AccessReceipt(AccessReceipt),
+    Profile(Profile),
+    Follow(Follow),
+    Proposal(Proposal),
+    Reactions(Reactions),
+    Reaction(Reaction),
+    Bounty(Bounty),
+    Flag(Flag),
+}
@@ -0,0 +1,31 @@
+//! Compact summaries returned through `set_return_data` by the read-only
+//! view instructions.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Returned by `SologInstruction::GetPostSummary`
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct PostSummary {
+    pub author: Pubkey,
+    pub title: Vec<u8>,
+    pub claps: u8,
+    pub comment_count: u8,
+    pub collaborators: Vec<Pubkey>,
+    pub pinned_comments: Vec<Pubkey>,
+    pub comments_locked: bool,
+    pub price: u64,
+    pub reposts: u32,
+    pub repost_of: Option<Pubkey>,
+    pub expires_at: Option<i64>,
+}
+
+/// Returned by `SologInstruction::GetComment`
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CommentSummary {
+    pub post: Pubkey,
+    pub author: Pubkey,
+    pub order: u8,
+    pub claps: u8,
+    pub content: Vec<u8>,
+}
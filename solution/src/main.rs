@@ -6,95 +6,12 @@ use sha2::Sha256;
 use sha2::Digest;
 use borsh::{to_vec, BorshDeserialize, BorshSerialize};
 use solana_program::pubkey::Pubkey;
-
-pub const MAX_COLLABORATORS: usize = 3;
-
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub enum HeapCommand {
-    Allocate {
-        size: u64,
-    },
-    Edit {
-        index: u64,
-        data: Vec<u8>,
-        resize: bool,
-    },
-    Search {
-        index: u64,
-    }
-}
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub struct HeapKit {
-    pub commands: Vec<HeapCommand>
-}
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub struct Post {
-    pub claps: u8,
-    pub comment_count: u8,
-
-    pub collaborators: [Pubkey; MAX_COLLABORATORS],
-    pub collaborator_count: u8,
-
-    pub author: Pubkey,
-    pub title: Vec<u8>,
-    pub content: Vec<u8>,
-}
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub struct Comment {
-    pub claps: u8,
-    pub order: u8,
-    pub author: Pubkey,
-    pub content: Vec<u8>,
-}
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub enum SologData {
-    Post(Post),
-    Comment(Comment),
-}
-
-#[derive(Debug, BorshSerialize, BorshDeserialize)]
-pub enum SologInstruction {
-    /// Create a new post
-    /// accounts:
-    ///  0. author
-    ///  1. post pda
-    ///  2. system_program
-    CreatePost { title: Vec<u8>, content: Vec<u8> },
-
-    /// Add a collaborator to a post
-    /// accounts:
-    ///  0. author
-    ///  1. collaborator
-    ///  2. post pda
-    AddCollaborator,
-
-    /// Clap for a post
-    /// accounts:
-    ///  0. viewer
-    ///  1. post or comment pda
-    ///  2. system_program
-    Clap { data: Vec<u8> },
-
-    /// Comment on a post
-    /// accounts:
-    ///  0. author
-    ///  1. post pda
-    ///  2. comment pda
-    ///  3. system_program
-    AddComment { content: Vec<u8> },
-
-    /// Edit a comment
-    /// accounts:
-    /// 0. author
-    /// 1. comment pda
-    /// 2. system_program
-    EditComment { content: Vec<u8> },
-}
+use solog_types::{
+    event::SologEvent,
+    instruction::{format_heap_dump, HeapCommand, HeapKit, SologInstruction},
+    pda::find_block_list_address,
+    state::{Comment, SologData},
+};
 
 pub struct ProofOfWork {
     pub prefix: String,
@@ -199,8 +116,11 @@ fn main() -> Result<(), Box<dyn Error>> {
     let comment_data = Comment {
         claps: 0, 
         order: 0,
+        flags: 0,
+        hidden: false,
         author: user,
         content: vec![],
+        rent_payer: user,
     };
     let comment_data = to_vec(&SologData::Comment(comment_data)).unwrap();
     let mut data = vec![0x41_u8; 29];
@@ -252,12 +172,15 @@ fn main() -> Result<(), Box<dyn Error>> {
     let edit_post = SologInstruction::EditComment {
         content: fake_content,
     };
+    let (block_list, _) = find_block_list_address(&program_id, &post);
     let edit_post_ix = Instruction {
         program_id,
         accounts: vec![
             AccountMeta::new(user, true),
             AccountMeta::new(post, false),
             AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new_readonly(post, false),
+            AccountMeta::new_readonly(block_list, false),
         ],
         data: to_vec(&edit_post).unwrap(),
     };
@@ -310,5 +233,14 @@ fn main() -> Result<(), Box<dyn Error>> {
     stream.read_to_end(&mut response)?;
     let response = String::from_utf8(response)?;
     println!("{}", response);
+
+    // the server echoes the transaction logs, print the solog events in them
+    let logs: Vec<&str> = response.lines().collect();
+    for event in SologEvent::from_logs(&logs) {
+        match event {
+            SologEvent::HeapDumped { chunks } => print!("{}", format_heap_dump(&chunks)),
+            event => println!("event: {:?}", event),
+        }
+    }
     Ok(())
 }